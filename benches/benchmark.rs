@@ -1,6 +1,15 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use etf::distributions::{Cauchy, CentralNormal, ChiSquared, Gumbel, Normal};
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
+use etf::distributions::{
+    Cauchy, CentralNormal, ChiDist, ChiDistDirect, ChiSquared, Gamma, Gumbel, HalfCauchy,
+    KumaraswamyETF, LogGamma, Normal,
+};
+use etf::primitives::partition::{
+    InitTable, Partition, P1024, P128, P2048, P256, P4096, P512, P64,
+};
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation};
+#[cfg(not(feature = "rand_distribution"))]
 use etf::primitives::Distribution as _;
+use etf::primitives::{DistAny, DistCentral};
 use rand::distributions::Distribution;
 use rand_core::SeedableRng;
 use rand_distr;
@@ -48,6 +57,28 @@ macro_rules! dist_benchmark_64 {
     };
 }
 
+// Uniform distribution over [-1, 1].
+fn make_central_uniform_64() -> DistCentral<P256<f64>, f64, fn(f64) -> f64> {
+    let mut table = InitTable::<P256<f64>, f64>::default();
+    for i in 0..=256 {
+        table.x[i] = i as f64 / 256.0;
+    }
+    for i in 0..256 {
+        table.yinf[i] = 1.0;
+        table.ysup[i] = 1.0;
+    }
+
+    DistCentral::new(|_| 1.0, &table)
+}
+
+dist_benchmark_64!(
+    central_uniform_64,
+    etf_central_uniform_64_bench,
+    rand_central_uniform_64_bench,
+    make_central_uniform_64(),
+    rand_distr::Uniform::new_inclusive(-1.0_f64, 1.0_f64)
+);
+
 dist_benchmark_32!(
     central_normal_32,
     etf_central_normal_32_bench,
@@ -88,6 +119,16 @@ dist_benchmark_64!(
     rand_distr::Cauchy::new(1.0_f64, 2.0_f64).unwrap()
 );
 
+dist_benchmark_64!(
+    half_cauchy_64,
+    etf_half_cauchy_64_bench,
+    rand_cauchy_abs_64_bench,
+    HalfCauchy::new(2.0_f64).unwrap(),
+    rand_distr::Cauchy::new(0.0_f64, 2.0_f64)
+        .unwrap()
+        .map(|x: f64| x.abs())
+);
+
 dist_benchmark_32!(
     gumbel_32,
     etf_gumberl_32_bench,
@@ -168,12 +209,217 @@ dist_benchmark_64!(
     rand_distr::ChiSquared::new(1000_f64).unwrap()
 );
 
+dist_benchmark_64!(
+    chi_64_k3,
+    etf_chi_64_k3_bench,
+    etf_chi_squared_sqrt_64_k3_bench,
+    ChiDist::new(3_f64).unwrap(),
+    rand_distr::ChiSquared::new(3_f64)
+        .unwrap()
+        .map(|x: f64| x.sqrt())
+);
+
+// Direct tabulation of the χ distribution versus square root of χ² samples,
+// for small and large numbers of degrees of freedom.
+fn chi_direct_vs_sqrt_64_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chi_direct_vs_sqrt_64");
+    for &k in &[3.0, 1000.0] {
+        let direct = ChiDistDirect::<f64>::new(k).unwrap();
+        let sqrt = ChiSquared::<f64>::new(k).unwrap();
+        let mut rng = Xoshiro256StarStar::seed_from_u64(0);
+        group.bench_function(BenchmarkId::new("direct", k), |b| {
+            b.iter(|| direct.sample(&mut rng))
+        });
+        group.bench_function(BenchmarkId::new("sqrt", k), |b| {
+            b.iter(|| sqrt.sample(&mut rng).sqrt())
+        });
+    }
+    group.finish();
+}
+
+fn chi_direct_vs_sqrt_32_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chi_direct_vs_sqrt_32");
+    for &k in &[3.0, 1000.0] {
+        let direct = ChiDistDirect::<f32>::new(k).unwrap();
+        let sqrt = ChiSquared::<f32>::new(k).unwrap();
+        let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+        group.bench_function(BenchmarkId::new("direct", k), |b| {
+            b.iter(|| direct.sample(&mut rng))
+        });
+        group.bench_function(BenchmarkId::new("sqrt", k), |b| {
+            b.iter(|| sqrt.sample(&mut rng).sqrt())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    chi_direct_vs_sqrt,
+    chi_direct_vs_sqrt_64_bench,
+    chi_direct_vs_sqrt_32_bench
+);
+
+dist_benchmark_64!(
+    log_gamma_64_k0_3,
+    etf_log_gamma_64_k0_3_bench,
+    rand_gamma_ln_64_k0_3_bench,
+    LogGamma::new(0.3_f64, 1_f64).unwrap(),
+    rand_distr::Gamma::new(0.3_f64, 1_f64)
+        .unwrap()
+        .map(|x: f64| x.ln())
+);
+
+dist_benchmark_64!(
+    log_gamma_64_k3,
+    etf_log_gamma_64_k3_bench,
+    rand_gamma_ln_64_k3_bench,
+    LogGamma::new(3_f64, 1_f64).unwrap(),
+    rand_distr::Gamma::new(3_f64, 1_f64)
+        .unwrap()
+        .map(|x: f64| x.ln())
+);
+
+// The rand benchmark samples the Kumaraswamy distribution by inversion.
+dist_benchmark_64!(
+    kumaraswamy_64,
+    etf_kumaraswamy_64_bench,
+    inversion_kumaraswamy_64_bench,
+    KumaraswamyETF::new(2_f64, 5_f64).unwrap(),
+    rand_distr::Uniform::new(0_f64, 1_f64).map(|u: f64| (1.0 - (1.0 - u).powf(0.2)).powf(0.5))
+);
+
+// Construction versus in-place retabulation of a distribution in a loop of
+// 10,000 parameter updates, each followed by a single sample.
+//
+// The same table is reused at each update so as to measure only the overhead
+// of table processing and allocation.
+fn make_normal_table_64() -> InitTable<P256<f64>, f64> {
+    let pdf = |x: f64| (-0.5 * x * x).exp();
+    let dpdf = |x: f64| -x * (-0.5 * x * x).exp();
+    let x_init = midpoint_prepartition::<P256<f64>, _, _>(&pdf, -3.0, 3.0, 0);
+
+    newton_tabulation(&pdf, &dpdf, &x_init, &[0.0], 1.0e-6, 1.0, 50).unwrap()
+}
+
+fn new_dist_any_update_64_bench(c: &mut Criterion) {
+    let pdf: fn(f64) -> f64 = |x| (-0.5 * x * x).exp();
+    let table = make_normal_table_64();
+    let mut rng = Xoshiro256StarStar::seed_from_u64(0);
+    c.bench_function("dist_any_update_64-new", |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+            for _ in 0..10_000 {
+                let dist = DistAny::new(pdf, &table);
+                sum += dist.sample(&mut rng);
+            }
+            sum
+        })
+    });
+}
+
+fn retabulate_dist_any_update_64_bench(c: &mut Criterion) {
+    let pdf: fn(f64) -> f64 = |x| (-0.5 * x * x).exp();
+    let table = make_normal_table_64();
+    let mut dist = DistAny::new(pdf, &table);
+    let mut rng = Xoshiro256StarStar::seed_from_u64(0);
+    c.bench_function("dist_any_update_64-retabulate", |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+            for _ in 0..10_000 {
+                dist.retabulate(pdf, &table);
+                sum += dist.sample(&mut rng);
+            }
+            sum
+        })
+    });
+}
+
+criterion_group!(
+    dist_any_update_64,
+    new_dist_any_update_64_bench,
+    retabulate_dist_any_update_64_bench
+);
+
+// Construction time of the table of a standard normal distribution over
+// [-4, 4] for various partition sizes.
+fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+fn normal_dpdf(x: f64) -> f64 {
+    -x * (-0.5 * x * x).exp()
+}
+
+fn bench_newton_tabulation<P: Partition<f64>, M: criterion::measurement::Measurement>(
+    group: &mut BenchmarkGroup<M>,
+) {
+    let x_init = midpoint_prepartition::<P, _, _>(&normal_pdf, -4.0, 4.0, 0);
+    group.bench_function(BenchmarkId::from_parameter(P::SIZE), |b| {
+        b.iter(|| {
+            newton_tabulation(&normal_pdf, &normal_dpdf, &x_init, &[0.0], 1.0e-6, 1.0, 50).unwrap()
+        })
+    });
+}
+
+fn bench_midpoint_prepartition<P: Partition<f64>, M: criterion::measurement::Measurement>(
+    group: &mut BenchmarkGroup<M>,
+) {
+    group.bench_function(BenchmarkId::from_parameter(P::SIZE), |b| {
+        b.iter(|| midpoint_prepartition::<P, _, _>(&normal_pdf, -4.0, 4.0, 0))
+    });
+}
+
+fn newton_tabulation_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("newton_tabulation");
+    bench_newton_tabulation::<P64<f64>, _>(&mut group);
+    bench_newton_tabulation::<P128<f64>, _>(&mut group);
+    bench_newton_tabulation::<P256<f64>, _>(&mut group);
+    bench_newton_tabulation::<P512<f64>, _>(&mut group);
+    bench_newton_tabulation::<P1024<f64>, _>(&mut group);
+    bench_newton_tabulation::<P2048<f64>, _>(&mut group);
+    bench_newton_tabulation::<P4096<f64>, _>(&mut group);
+    group.finish();
+}
+
+fn midpoint_prepartition_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("midpoint_prepartition");
+    bench_midpoint_prepartition::<P64<f64>, _>(&mut group);
+    bench_midpoint_prepartition::<P128<f64>, _>(&mut group);
+    bench_midpoint_prepartition::<P256<f64>, _>(&mut group);
+    bench_midpoint_prepartition::<P512<f64>, _>(&mut group);
+    bench_midpoint_prepartition::<P1024<f64>, _>(&mut group);
+    bench_midpoint_prepartition::<P2048<f64>, _>(&mut group);
+    bench_midpoint_prepartition::<P4096<f64>, _>(&mut group);
+    group.finish();
+}
+
+// Construction time of a Γ distribution with unit scale, including the
+// tabulation of the P512 table, for small and large shape parameters.
+fn gamma_construction_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gamma_construction");
+    for &shape in &[0.3, 3.0] {
+        group.bench_function(BenchmarkId::from_parameter(shape), |b| {
+            b.iter(|| Gamma::<f64>::new(shape, 1.0).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    tabulation,
+    newton_tabulation_bench,
+    midpoint_prepartition_bench,
+    gamma_construction_bench
+);
+
 criterion_main!(
+    central_uniform_64,
     central_normal_32,
     central_normal_64,
     normal_64,
     cauchy_32,
     cauchy_64,
+    half_cauchy_64,
     gumbel_32,
     gumbel_64,
     chi_squared_32_k0_5,
@@ -184,4 +430,11 @@ criterion_main!(
     chi_squared_64_k5,
     chi_squared_32_k1000,
     chi_squared_64_k1000,
+    chi_64_k3,
+    chi_direct_vs_sqrt,
+    log_gamma_64_k0_3,
+    log_gamma_64_k3,
+    kumaraswamy_64,
+    dist_any_update_64,
+    tabulation,
 );
@@ -0,0 +1,159 @@
+//! Sampling of the posterior of a Bayesian logistic regression.
+//!
+//! A single regression coefficient `β` is inferred from observations `yᵢ ∈
+//! {0, 1}` generated with probability `σ(β xᵢ)`, where `σ` is the logistic
+//! function, assuming a normal prior `N(0, s²)`. The log-posterior is, up to a
+//! constant:
+//!
+//! ```text
+//! ln p(β) = Σ [yᵢ β xᵢ - ln(1 + exp(β xᵢ))] - β² / (2 s²)
+//! ```
+//!
+//! The posterior is log-concave, so that the tangents of `ln p` at the tail
+//! cut-in positions provide exponential envelopes for both tails. These are
+//! expressed as Weibull envelopes with unit shape parameter.
+use etf::primitives::partition::P256;
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation, WeibullEnvelope};
+use etf::primitives::{DistAnyTailed, Distribution, TryDistribution};
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
+use rand_distr::StandardNormal;
+use rand_pcg::Pcg64;
+
+const TRUE_BETA: f64 = 1.5;
+const PRIOR_STD_DEV: f64 = 10.0;
+const OBSERVATION_COUNT: usize = 200;
+const SAMPLE_COUNT: usize = 1_000_000;
+// Tail positions relative to the mode, in units of the Laplace standard deviation.
+const TAIL_POS: f64 = 4.0;
+
+/// Numerically stable `ln(1 + exp(z))`.
+fn softplus(z: f64) -> f64 {
+    if z > 0.0 {
+        z + (-z).exp().ln_1p()
+    } else {
+        z.exp().ln_1p()
+    }
+}
+
+fn sigmoid(z: f64) -> f64 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+/// Combined left & right tail envelope.
+struct Tails<F, G> {
+    left: WeibullEnvelope<f64, F>,
+    right: WeibullEnvelope<f64, G>,
+    left_tail_weight: f64,
+}
+
+impl<F: Fn(f64) -> f64, G: Fn(f64) -> f64> TryDistribution<f64> for Tails<F, G> {
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<f64> {
+        if rng.gen::<f64>() < self.left_tail_weight {
+            self.left.try_sample(rng)
+        } else {
+            self.right.try_sample(rng)
+        }
+    }
+}
+
+fn main() {
+    let mut rng = Pcg64::seed_from_u64(0);
+
+    // Synthetic observations.
+    let data: Vec<(f64, f64)> = (0..OBSERVATION_COUNT)
+        .map(|_| {
+            let x: f64 = rng.sample(StandardNormal);
+            let y = if rng.gen::<f64>() < sigmoid(TRUE_BETA * x) {
+                1.0
+            } else {
+                0.0
+            };
+            (x, y)
+        })
+        .collect();
+
+    // Log-posterior and its first two derivatives.
+    let inv_prior_var = 1.0 / (PRIOR_STD_DEV * PRIOR_STD_DEV);
+    let ln_p = |beta: f64| {
+        data.iter()
+            .map(|&(x, y)| y * beta * x - softplus(beta * x))
+            .sum::<f64>()
+            - 0.5 * inv_prior_var * beta * beta
+    };
+    let d_ln_p = |beta: f64| {
+        data.iter()
+            .map(|&(x, y)| (y - sigmoid(beta * x)) * x)
+            .sum::<f64>()
+            - inv_prior_var * beta
+    };
+    let d2_ln_p = |beta: f64| {
+        -data
+            .iter()
+            .map(|&(x, _)| {
+                let s = sigmoid(beta * x);
+                s * (1.0 - s) * x * x
+            })
+            .sum::<f64>()
+            - inv_prior_var
+    };
+
+    // Locate the mode with Newton's method and estimate the posterior width
+    // with the Laplace approximation.
+    let mut mode = 0.0;
+    for _ in 0..50 {
+        mode -= d_ln_p(mode) / d2_ln_p(mode);
+    }
+    let laplace_std_dev = (-1.0 / d2_ln_p(mode)).sqrt();
+
+    // Posterior normalized by its value at the mode, and its derivative.
+    let ln_p_mode = ln_p(mode);
+    let pdf = |beta: f64| (ln_p(beta) - ln_p_mode).exp();
+    let dpdf = |beta: f64| d_ln_p(beta) * pdf(beta);
+
+    // Tabulation of the bulk of the posterior.
+    let x_left = mode - TAIL_POS * laplace_std_dev;
+    let x_right = mode + TAIL_POS * laplace_std_dev;
+    let x_init = midpoint_prepartition::<P256<f64>, _, _>(&pdf, x_left, x_right, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[mode], 1.0e-6, 1.0, 50)
+        .expect("the tabulation did not converge");
+
+    // Exponential tail envelopes: for a Weibull envelope with unit shape
+    // parameter, the location coincides with the cut-in position and the
+    // weight is the area under the envelope.
+    let b_left = -1.0 / d_ln_p(x_left);
+    let b_right = -1.0 / d_ln_p(x_right);
+    let w_left = pdf(x_left) * b_left.abs();
+    let w_right = pdf(x_right) * b_right;
+    let left = WeibullEnvelope::new(w_left, 1.0, b_left, x_left, x_left, pdf);
+    let right = WeibullEnvelope::new(w_right, 1.0, b_right, x_right, x_right, pdf);
+    let tail_area = left.area() + right.area();
+    let tails = Tails {
+        left_tail_weight: left.area() / tail_area,
+        left,
+        right,
+    };
+    let posterior = DistAnyTailed::new(pdf, &table, tails, tail_area);
+
+    // Posterior statistics.
+    let mut samples: Vec<f64> = (0..SAMPLE_COUNT)
+        .map(|_| posterior.sample(&mut rng))
+        .collect();
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mean = samples.iter().sum::<f64>() / SAMPLE_COUNT as f64;
+    let lower = samples[SAMPLE_COUNT / 40];
+    let upper = samples[SAMPLE_COUNT - SAMPLE_COUNT / 40];
+
+    println!("true coefficient:         {:.4}", TRUE_BETA);
+    println!(
+        "posterior mode (Laplace): {:.4} ± {:.4}",
+        mode, laplace_std_dev
+    );
+    println!("posterior mean:           {:.4}", mean);
+    println!("95% credible interval:    [{:.4}, {:.4}]", lower, upper);
+    if lower <= TRUE_BETA && TRUE_BETA <= upper {
+        println!("the true coefficient lies within the credible interval");
+    } else {
+        println!("the true coefficient lies outside the credible interval");
+    }
+}
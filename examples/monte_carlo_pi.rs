@@ -0,0 +1,144 @@
+//! Monte Carlo estimation of π with ETF and `rand` distributions.
+//!
+//! Three estimators are compared:
+//!
+//! * the classical acceptance-rejection estimator, which counts the points
+//!   `(X, Y)` uniformly distributed over `[-1, 1]²` that fall within the unit
+//!   disk, so that `π ≈ 4 P(X² + Y² ≤ 1)`,
+//! * the same acceptance test as in the polar Box-Muller method but applied to
+//!   pairs `(X, Y)` of standard normal variates; since the fraction of such
+//!   pairs within the unit disk, `1 - e^(-1/2)`, does not involve π, each pair
+//!   is weighted by `w = exp((X² + Y²)/2)` so as to cancel the normal density,
+//!   and `π ≈ 4 E[w 1(X² + Y² ≤ 1)] / E[w 1(|X| ≤ 1, |Y| ≤ 1)]`,
+//! * an estimator based on the standard normal distribution, for which
+//!   `E[|X|] = √(2/π)`, so that `π ≈ 2 / E[|X|]²`.
+//!
+//! The uniform distribution is a `DistCentral` built from a trivial table with
+//! equal `yinf` and `ysup`, so that the wedge test is never needed.
+use etf::distributions::CentralNormal;
+use etf::primitives::partition::{InitTable, P256};
+use etf::primitives::DistCentral;
+#[cfg(not(feature = "rand_distribution"))]
+use etf::primitives::Distribution as _;
+use rand::distributions::{Distribution, Uniform};
+use rand_core::SeedableRng;
+use rand_distr::StandardNormal;
+use rand_xoshiro::Xoshiro256StarStar;
+use std::time::{Duration, Instant};
+
+const SAMPLE_COUNT: usize = 100_000_000;
+
+/// Uniform distribution over `[-1, 1]`.
+fn central_uniform() -> DistCentral<P256<f64>, f64, fn(f64) -> f64> {
+    let mut table = InitTable::<P256<f64>, f64>::default();
+    for i in 0..=256 {
+        table.x[i] = i as f64 / 256.0;
+    }
+    for i in 0..256 {
+        table.yinf[i] = 1.0;
+        table.ysup[i] = 1.0;
+    }
+
+    DistCentral::new(|_| 1.0, &table)
+}
+
+/// Returns the estimate of π and the elapsed time.
+fn timed<F: FnOnce() -> f64>(f: F) -> (f64, Duration) {
+    let now = Instant::now();
+    let pi = f();
+
+    (pi, now.elapsed())
+}
+
+/// Estimates π from pairs of standard normal variates by weighting the
+/// acceptance-rejection test of the polar Box-Muller method with the inverse
+/// of the (unnormalized) normal density.
+fn weighted_acceptance<I: Iterator<Item = (f64, f64)>>(pairs: I) -> f64 {
+    let (disk, square) = pairs
+        .filter(|&(x, y)| x.abs() <= 1.0 && y.abs() <= 1.0)
+        .fold((0.0, 0.0), |(disk, square), (x, y)| {
+            let r2 = x * x + y * y;
+            let w = (0.5 * r2).exp();
+            if r2 <= 1.0 {
+                (disk + w, square + w)
+            } else {
+                (disk, square + w)
+            }
+        });
+
+    4.0 * disk / square
+}
+
+fn report(name: &str, etf: (f64, Duration), rand: (f64, Duration)) {
+    println!("{}:", name);
+    println!("    etf:     π ≈ {:.6} in {:?}", etf.0, etf.1);
+    println!("    rand:    π ≈ {:.6} in {:?}", rand.0, rand.1);
+    println!(
+        "    speedup: {:.2}",
+        rand.1.as_secs_f64() / etf.1.as_secs_f64()
+    );
+}
+
+fn main() {
+    let mut rng = Xoshiro256StarStar::seed_from_u64(0);
+
+    // Acceptance-rejection estimator.
+    let etf_uniform = central_uniform();
+    let etf = timed(|| {
+        let hits = (0..SAMPLE_COUNT)
+            .filter(|_| {
+                let x = etf_uniform.sample(&mut rng);
+                let y = etf_uniform.sample(&mut rng);
+                x * x + y * y <= 1.0
+            })
+            .count();
+        4.0 * hits as f64 / SAMPLE_COUNT as f64
+    });
+    let rand_uniform = Uniform::new_inclusive(-1.0, 1.0);
+    let rand = timed(|| {
+        let hits = (0..SAMPLE_COUNT)
+            .filter(|_| {
+                let x: f64 = rand_uniform.sample(&mut rng);
+                let y: f64 = rand_uniform.sample(&mut rng);
+                x * x + y * y <= 1.0
+            })
+            .count();
+        4.0 * hits as f64 / SAMPLE_COUNT as f64
+    });
+    report("Uniform acceptance-rejection", etf, rand);
+
+    // Weighted acceptance-rejection estimator with normal pairs.
+    let etf_normal = CentralNormal::new(1.0_f64).unwrap();
+    let etf = timed(|| {
+        weighted_acceptance((0..SAMPLE_COUNT).map(|_| {
+            let x = etf_normal.sample(&mut rng);
+            let y = etf_normal.sample(&mut rng);
+            (x, y)
+        }))
+    });
+    let rand = timed(|| {
+        weighted_acceptance((0..SAMPLE_COUNT).map(|_| {
+            let x: f64 = Distribution::<f64>::sample(&StandardNormal, &mut rng);
+            let y: f64 = Distribution::<f64>::sample(&StandardNormal, &mut rng);
+            (x, y)
+        }))
+    });
+    report("Normal weighted acceptance-rejection", etf, rand);
+
+    // Normal estimator.
+    let etf = timed(|| {
+        let sum: f64 = (0..SAMPLE_COUNT)
+            .map(|_| etf_normal.sample(&mut rng).abs())
+            .sum();
+        let mean = sum / SAMPLE_COUNT as f64;
+        2.0 / (mean * mean)
+    });
+    let rand = timed(|| {
+        let sum: f64 = (0..SAMPLE_COUNT)
+            .map(|_| Distribution::<f64>::sample(&StandardNormal, &mut rng).abs())
+            .sum();
+        let mean = sum / SAMPLE_COUNT as f64;
+        2.0 / (mean * mean)
+    });
+    report("Normal mean absolute value", etf, rand);
+}
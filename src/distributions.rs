@@ -1,13 +1,64 @@
 //! ETF-based implementation of common continuous probability distributions.
 
 pub use cauchy::{Cauchy, CauchyError, CauchyFloat};
+pub use chi::{ChiDist, ChiDistDirect, ChiError, ChiFloat};
 pub use chi_squared::{ChiSquared, ChiSquaredError, ChiSquaredFloat};
-pub use gamma::{Gamma, GammaError, GammaFloat};
+pub use circular::{WrappedCauchy, WrappedCauchyError, WrappedNormal, WrappedNormalError};
+pub use gamma::{Gamma, GammaError, GammaFloat, GammaPrecision, LogGamma};
 pub use gumbel::{Gumbel, GumbelError, GumbelFloat};
+pub use half_cauchy::{HalfCauchy, HalfCauchyError};
+pub use inverse_chi_squared::{InverseChi2, InverseChi2Error, ScaledInverseChi2};
+pub use kumaraswamy::{Kumaraswamy, KumaraswamyETF, KumaraswamyError, KumaraswamyFloat};
+pub use log_ratio::{
+    LogOddsBeta, LogOddsBetaError, LogRatioFloat, LogRatioNormal, LogRatioNormalError,
+};
+pub use maxwell_boltzmann::{MaxwellBoltzmann, MaxwellBoltzmannError, MaxwellBoltzmannFloat};
 pub use normal::{CentralNormal, Normal, NormalError, NormalFloat};
+pub use stable::{StableError, StableFloat, SymmetricStable};
 
 mod cauchy;
+mod chi;
 mod chi_squared;
+mod circular;
 mod gamma;
 mod gumbel;
+mod half_cauchy;
+mod inverse_chi_squared;
+mod kumaraswamy;
+mod log_ratio;
+mod maxwell_boltzmann;
 mod normal;
+mod stable;
+
+#[cfg(feature = "sorted_samples")]
+use crate::num::Float;
+#[cfg(feature = "sorted_samples")]
+use rand_core::RngCore;
+
+/// Generates `n` samples in ascending order by inversion of the quantile
+/// function.
+///
+/// The order statistics `U₍₁₎ ≤ ... ≤ U₍ₙ₎` of `n` uniform variates are
+/// generated in `O(n)` from the top down with `U₍ₖ₎ = U₍ₖ₊₁₎ Vₖ^(1/k)`, where
+/// `U₍ₙ₊₁₎ = 1` and `Vₖ` are independent uniform variates, and then mapped
+/// through the quantile function.
+#[cfg(feature = "sorted_samples")]
+fn sample_sorted<T, R, Q>(rng: &mut R, n: usize, quantile: Q) -> Vec<T>
+where
+    T: Float,
+    R: RngCore + ?Sized,
+    Q: Fn(T) -> T,
+{
+    // Offset which maps the output of `T::gen` into the open interval (0, 1).
+    let offset = T::from(0.25) * T::EPSILON;
+
+    let mut samples = vec![T::ZERO; n];
+    let mut u = T::ONE;
+    for k in (1..=n).rev() {
+        let v = T::gen(rng) + offset;
+        u *= v.powf(T::ONE / T::cast_usize(k));
+        samples[k - 1] = quantile(u);
+    }
+
+    samples
+}
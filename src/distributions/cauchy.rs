@@ -57,6 +57,8 @@ pub enum CauchyError {
 #[derive(Clone)]
 pub struct Cauchy<T: CauchyFloat> {
     inner: DistSymmetricTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    location: T,
+    scale: T,
 }
 
 impl<T: CauchyFloat> Cauchy<T> {
@@ -85,8 +87,34 @@ impl<T: CauchyFloat> Cauchy<T> {
         let (tail_func, tail_area) = Tail::new_with_area(location, scale);
         Ok(Self {
             inner: DistSymmetricTailed::new(location, pdf, &table, tail_func, tail_area),
+            location,
+            scale,
         })
     }
+
+    /// Returns the location parameter.
+    pub fn location(&self) -> T {
+        self.location
+    }
+
+    /// Returns the scale parameter.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    pub fn quantile(&self, p: T) -> T {
+        self.location + self.scale * T::tan(T::PI * (p - T::ONE_HALF))
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained in `O(n)` by inversion of the quantile
+    /// function at sorted uniform variates.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        super::sample_sorted(rng, n, |p| self.quantile(p))
+    }
 }
 
 impl<T: CauchyFloat> Distribution<T> for Cauchy<T> {
@@ -99,13 +127,13 @@ impl<T: CauchyFloat> Distribution<T> for Cauchy<T> {
 /// Non-normalized Cauchy probability distribution function with arbitrary
 /// location and scale.
 #[derive(Copy, Clone, Debug)]
-struct UnscaledPdf<T> {
+pub(super) struct UnscaledPdf<T> {
     location: T,
     square_inv_scale: T,
 }
 
 impl<T: Float> UnscaledPdf<T> {
-    fn new(location: T, scale: T) -> Self {
+    pub(super) fn new(location: T, scale: T) -> Self {
         Self {
             location,
             square_inv_scale: T::ONE / (scale * scale),
@@ -114,14 +142,14 @@ impl<T: Float> UnscaledPdf<T> {
 }
 
 impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
-    #[inline]
+    #[inline(always)]
     fn eval(&self, x: T) -> T {
         let dx = x - self.location;
 
         T::ONE / (T::ONE + self.square_inv_scale * dx * dx)
     }
 
-    #[inline]
+    #[inline(always)]
     fn test(&self, x: T, a: T, b: T) -> bool {
         let dx = x - self.location;
 
@@ -130,7 +158,7 @@ impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
 }
 
 #[derive(Copy, Clone, Debug)]
-struct Tail<T> {
+pub(super) struct Tail<T> {
     location: T,
     scale: T,
     a: T,
@@ -138,7 +166,7 @@ struct Tail<T> {
 }
 
 impl<T: CauchyFloat> Tail<T> {
-    fn new_with_area(location: T, scale: T) -> (Self, T) {
+    pub(super) fn new_with_area(location: T, scale: T) -> (Self, T) {
         let fmin = T::atan(T::TAIL_POS) / T::PI + T::ONE_HALF;
 
         let tail = Self {
@@ -160,3 +188,83 @@ impl<T: Float> TryDistribution<T> for Tail<T> {
         Some(self.location + self.scale * T::tan(self.a * T::gen(rng) + self.b))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::Rng;
+    use rand_pcg::Lcg128Xsl64;
+
+    // Reference implementation of the rejection test.
+    fn reference_test(pdf: &UnscaledPdf<f64>, x: f64, a: f64, b: f64) -> bool {
+        a * pdf.eval(x) > b
+    }
+
+    #[test]
+    fn unscaled_pdf_test_corner_cases() {
+        let location = -1.7;
+        let pdf = UnscaledPdf::new(location, 2.8);
+        let xs = [
+            location,
+            location + 1.0,
+            location - 1.0e-300,
+            location + 1.0e150,
+            location - 1.0e200,
+            f64::MAX,
+            f64::INFINITY,
+            -f64::INFINITY,
+        ];
+        // Tiny values of `a` are omitted since `a * f(x)` may then underflow
+        // in the reference test whereas the optimized test remains exact.
+        let as_ = [0.0, 0.25, 0.5, 1.0, 3.0, f64::MAX];
+        let bs = [0.0, 1.0e-300, 0.25, 0.5, 1.0, 3.0, f64::MAX];
+
+        for &x in xs.iter() {
+            for &a in as_.iter() {
+                for &b in bs.iter() {
+                    assert_eq!(
+                        pdf.test(x, a, b),
+                        reference_test(&pdf, x, a, b),
+                        "x = {}, a = {}, b = {}",
+                        x,
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unscaled_pdf_test_random() {
+        let mut rng = Lcg128Xsl64::new(0xcafef00dd15ea5e5, 0xa02bdbf7bb3c0a7ac28fa16a64abf96);
+
+        for _ in 0..100_000 {
+            let location: f64 = rng.gen_range(-1.0e3..1.0e3);
+            let scale = 10_f64.powf(rng.gen_range(-5.0..5.0));
+            let pdf = UnscaledPdf::new(location, scale);
+            let x = location
+                + scale * 10_f64.powf(rng.gen_range(-3.0..10.0)) * rng.gen_range(-1.0..1.0);
+            let a: f64 = rng.gen();
+            let b: f64 = rng.gen();
+
+            // Both forms are only expected to agree up to rounding errors
+            // when `a * f(x)` and `b` are nearly equal.
+            let lhs = a * pdf.eval(x);
+            if (lhs - b).abs() <= 4.0 * f64::EPSILON * lhs.max(b) {
+                continue;
+            }
+            assert_eq!(
+                pdf.test(x, a, b),
+                reference_test(&pdf, x, a, b),
+                "location = {}, scale = {}, x = {}, a = {}, b = {}",
+                location,
+                scale,
+                x,
+                a,
+                b
+            );
+        }
+    }
+}
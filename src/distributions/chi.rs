@@ -0,0 +1,262 @@
+use crate::num::Float;
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+use super::chi_squared::{ChiSquared, ChiSquaredError, ChiSquaredFloat};
+
+/// A floating point type for use with χ distributions.
+pub trait ChiFloat: ChiSquaredFloat {
+    #[doc(hidden)]
+    const DIRECT_TAIL_OFFSET: Self;
+}
+
+impl ChiFloat for f32 {
+    #[doc(hidden)]
+    const DIRECT_TAIL_OFFSET: Self = 3.0;
+}
+
+impl ChiFloat for f64 {
+    #[doc(hidden)]
+    const DIRECT_TAIL_OFFSET: Self = 3.0;
+}
+
+/// Error type for χ distribution construction failures.
+#[derive(Error, Debug)]
+pub enum ChiError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// The number of degrees of freedom is not strictly positive.
+    #[error("the number of degrees of freedom should be strictly positive")]
+    BadDof,
+    /// The number of degrees of freedom is lower than 1, which makes the
+    /// probability density function singular at the origin.
+    #[error("the number of degrees of freedom should be at least 1")]
+    SingularDof,
+}
+
+/// The χ distribution.
+///
+/// The probability density function is:
+///
+/// ```text
+/// f(x) = 2^(1 - k / 2) x^(k - 1) exp(-x² / 2) / Γ(k / 2)
+/// ```
+///
+/// where `x ≥ 0` and where the number of degrees of freedom `k` is strictly
+/// positive.
+///
+/// For `k ≥ 1`, samples are generated with [`ChiDistDirect`], which is faster
+/// than taking the square root of χ² samples for small `k` and on par for
+/// large `k`. For
+/// `k < 1`, the probability density function is singular at the origin and
+/// samples are generated as the square root of χ² samples; this is also the
+/// fallback if the direct tabulation fails.
+#[derive(Clone)]
+pub struct ChiDist<T: ChiFloat> {
+    inner: ChiInner<T>,
+}
+
+impl<T: ChiFloat> ChiDist<T> {
+    /// Constructs a χ distribution with the specified number of degrees of
+    /// freedom.
+    pub fn new(k: T) -> Result<Self, ChiError> {
+        if k >= T::ONE {
+            if let Ok(direct) = ChiDistDirect::new(k) {
+                return Ok(Self {
+                    inner: ChiInner::Direct(direct),
+                });
+            }
+        }
+        match ChiSquared::new(k) {
+            Ok(inner) => Ok(Self {
+                inner: ChiInner::Sqrt(inner),
+            }),
+            Err(ChiSquaredError::TabulationFailure) => Err(ChiError::TabulationFailure),
+            Err(ChiSquaredError::BadDof) => Err(ChiError::BadDof),
+        }
+    }
+
+    /// Returns the number of degrees of freedom `k`.
+    pub fn dof(&self) -> T {
+        match &self.inner {
+            ChiInner::Direct(f) => f.dof(),
+            ChiInner::Sqrt(f) => f.dof(),
+        }
+    }
+}
+
+impl<T: ChiFloat> Distribution<T> for ChiDist<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        match &self.inner {
+            ChiInner::Direct(f) => f.sample(rng),
+            ChiInner::Sqrt(f) => f.sample(rng).sqrt(),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum ChiInner<T: ChiFloat> {
+    Direct(ChiDistDirect<T>),
+    Sqrt(ChiSquared<T>),
+}
+
+/// The χ distribution, sampled directly from its probability density
+/// function.
+///
+/// The probability density function is:
+///
+/// ```text
+/// f(x) = 2^(1 - k / 2) x^(k - 1) exp(-x² / 2) / Γ(k / 2)
+/// ```
+///
+/// where `x ≥ 0` and where the number of degrees of freedom `k` is not lower
+/// than 1, since the probability density function is otherwise singular at
+/// the origin.
+///
+/// The right tail is sampled using the fact that `u = x² / 2` follows a gamma
+/// distribution with shape `s = k / 2`. Beyond the cut-in position `uₜ`, the
+/// concavity of `ln(u)` yields for `s ≥ 1` the envelope:
+///
+/// ```text
+/// u^(s - 1) exp(-u) ≤ uₜ^(s - 1) exp(-u + (s - 1)(u - uₜ) / uₜ)
+/// ```
+///
+/// while for `s < 1` the envelope is simply `uₜ^(s - 1) exp(-u)`. In both
+/// cases this is a shifted exponential distribution in `u`.
+#[derive(Clone)]
+pub struct ChiDistDirect<T: ChiFloat> {
+    inner: DistAnyTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    dof: T,
+}
+
+impl<T: ChiFloat> ChiDistDirect<T> {
+    /// Constructs a χ distribution with the specified number of degrees of
+    /// freedom.
+    pub fn new(k: T) -> Result<Self, ChiError> {
+        if k <= T::ZERO {
+            return Err(ChiError::BadDof);
+        }
+        if k < T::ONE {
+            return Err(ChiError::SingularDof);
+        }
+        let pdf = UnscaledPdf::new(k);
+        let dpdf = move |x: T| pdf.eval(x) * (pdf.a / x - x);
+
+        let mode = pdf.a.sqrt();
+        let tail_position = mode + T::DIRECT_TAIL_OFFSET;
+        let init_nodes = util::midpoint_prepartition(&pdf, T::ZERO, tail_position, 0);
+        let table =
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, &[mode], T::TOLERANCE, T::ONE, 50)
+                .map_err(|_| ChiError::TabulationFailure)?;
+        let (tail_func, tail_area) = Tail::new_with_area(&pdf, tail_position);
+
+        Ok(Self {
+            inner: DistAnyTailed::new(pdf, &table, tail_func, tail_area),
+            dof: k,
+        })
+    }
+
+    /// Returns the number of degrees of freedom `k`.
+    pub fn dof(&self) -> T {
+        self.dof
+    }
+}
+
+impl<T: ChiFloat> Distribution<T> for ChiDistDirect<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
+
+/// χ probability distribution function normalized by its maximum.
+///
+/// ```text
+/// f(x) = exp(a ln(x) + c - x² / 2)
+/// ```
+///
+/// with `a = k - 1` and where `c = a (1 - ln(a)) / 2` normalizes the function
+/// by its value at the mode `√a`.
+#[derive(Copy, Clone, Debug)]
+struct UnscaledPdf<T> {
+    a: T,
+    c: T,
+}
+
+impl<T: Float> UnscaledPdf<T> {
+    fn new(k: T) -> Self {
+        let a = k - T::ONE;
+        let c = if a > T::ZERO {
+            T::ONE_HALF * a * (T::ONE - a.ln())
+        } else {
+            T::ZERO
+        };
+
+        Self { a, c }
+    }
+}
+
+impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
+    #[inline(always)]
+    fn eval(&self, x: T) -> T {
+        // The power term is skipped for `k = 1` to avoid evaluating `0 ln(0)`.
+        let ln_power = if self.a > T::ZERO {
+            self.a * x.ln()
+        } else {
+            T::ZERO
+        };
+
+        (ln_power + self.c - T::ONE_HALF * x * x).exp()
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Tail<T> {
+    cut_in: T,      // uₜ
+    inv_rate: T,    // 1 / (1 - max(s - 1, 0) / uₜ)
+    power: T,       // s - 1
+    excess_rate: T, // max(s - 1, 0) / uₜ
+}
+
+impl<T: Float> Tail<T> {
+    fn new_with_area(pdf: &UnscaledPdf<T>, tail_position: T) -> (Self, T) {
+        let shape = T::ONE_HALF * (pdf.a + T::ONE);
+        let power = shape - T::ONE;
+        let cut_in = T::ONE_HALF * tail_position * tail_position;
+        let excess_rate = power.max(T::ZERO) / cut_in;
+
+        let tail = Self {
+            cut_in,
+            inv_rate: T::ONE / (T::ONE - excess_rate),
+            power,
+            excess_rate,
+        };
+
+        // In terms of `u`, the envelope of `exp(a ln(x) + c - x² / 2)` reads
+        // `exp(c) 2^(s - 1) uₜ^(s - 1) exp(-uₜ - r (u - uₜ))` with rate
+        // `r = 1 - max(s - 1, 0) / uₜ`, hence its area beyond `uₜ`.
+        let rate = T::ONE - excess_rate;
+        let area = (pdf.c + power * (T::TWO * cut_in).ln() - cut_in).exp() / rate;
+
+        (tail, area)
+    }
+}
+
+impl<T: Float> TryDistribution<T> for Tail<T> {
+    #[inline(always)]
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let u = self.cut_in - self.inv_rate * (T::ONE - T::gen(rng)).ln();
+
+        if self.power * (u / self.cut_in).ln() - (u - self.cut_in) * self.excess_rate
+            >= (T::ONE - T::gen(rng)).ln()
+        {
+            Some((T::TWO * u).sqrt())
+        } else {
+            None
+        }
+    }
+}
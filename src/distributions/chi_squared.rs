@@ -24,7 +24,7 @@ pub enum ChiSquaredError {
 }
 
 /// The χ² distribution.
-/// 
+///
 /// The probability density function is:
 ///
 /// ```text
@@ -48,6 +48,29 @@ impl<T: ChiSquaredFloat> ChiSquared<T> {
             Err(GammaError::BadScale) => unreachable!(),
         }
     }
+
+    /// Returns the number of degrees of freedom `k`.
+    pub fn dof(&self) -> T {
+        T::TWO * self.inner.shape()
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    ///
+    /// The quantile is computed by bisection of the cumulative distribution
+    /// function.
+    pub fn quantile(&self, p: T) -> T {
+        self.inner.quantile(p)
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained by inversion of the quantile function at
+    /// sorted uniform variates. Since the quantile is computed by bisection,
+    /// this is typically slower than sorting regular samples.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        self.inner.sample_sorted(rng, n)
+    }
 }
 
 impl<T: ChiSquaredFloat> Distribution<T> for ChiSquared<T> {
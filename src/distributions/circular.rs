@@ -0,0 +1,135 @@
+use super::normal::{CentralNormal, NormalError, NormalFloat};
+use crate::num::Float;
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+/// Error type for wrapped Cauchy distribution construction failures.
+#[derive(Error, Debug)]
+pub enum WrappedCauchyError {
+    /// The provided concentration is not within `[0, 1)`.
+    #[error("the concentration should be within [0, 1)")]
+    BadConcentration,
+}
+
+/// The wrapped Cauchy distribution.
+///
+/// The probability density function over `(-π, π]` is:
+///
+/// ```text
+/// f(x) = (1 - ρ²) / (2π (1 + ρ² - 2ρ cos(x - μ)))
+/// ```
+///
+/// where `μ` is the mean direction and where the concentration `ρ` lies
+/// within `[0, 1)`.
+///
+/// The distribution is sampled exactly by inversion of its cumulative
+/// distribution function.
+#[derive(Copy, Clone, Debug)]
+pub struct WrappedCauchy<T> {
+    mean: T,
+    concentration: T,
+    c: T, // (1 - ρ) / (1 + ρ)
+}
+
+impl<T: Float> WrappedCauchy<T> {
+    /// Constructs a wrapped Cauchy distribution with the specified mean
+    /// direction and concentration.
+    pub fn new(mean: T, concentration: T) -> Result<Self, WrappedCauchyError> {
+        if !(concentration >= T::ZERO && concentration < T::ONE) {
+            return Err(WrappedCauchyError::BadConcentration);
+        }
+
+        Ok(Self {
+            mean,
+            concentration,
+            c: (T::ONE - concentration) / (T::ONE + concentration),
+        })
+    }
+
+    /// Returns the mean direction `μ`.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    /// Returns the concentration `ρ`.
+    pub fn concentration(&self) -> T {
+        self.concentration
+    }
+}
+
+impl<T: Float> Distribution<T> for WrappedCauchy<T> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        let t = T::tan(T::PI * (T::gen(rng) - T::ONE_HALF));
+
+        wrap(self.mean + T::TWO * T::atan(self.c * t))
+    }
+}
+
+/// Error type for wrapped normal distribution construction failures.
+#[derive(Error, Debug)]
+pub enum WrappedNormalError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// The provided standard deviation is not strictly positive.
+    #[error("the standard deviation should be strictly positive")]
+    BadStdDev,
+}
+
+/// The wrapped normal distribution.
+///
+/// The probability density function over `(-π, π]` is:
+///
+/// ```text
+/// f(x) = Σₖ exp(-½ (x - μ + 2πk)² / σ²) / (σ √(2π))
+/// ```
+///
+/// where `μ` is the mean direction and where the standard deviation `σ` of
+/// the unwrapped normal distribution is strictly positive.
+///
+/// The distribution is sampled by reducing normal samples to `(-π, π]`.
+#[derive(Clone)]
+pub struct WrappedNormal<T: NormalFloat> {
+    inner: CentralNormal<T>,
+    mean: T,
+}
+
+impl<T: NormalFloat> WrappedNormal<T> {
+    /// Constructs a wrapped normal distribution with the specified mean
+    /// direction and standard deviation.
+    pub fn new(mean: T, std_dev: T) -> Result<Self, WrappedNormalError> {
+        match CentralNormal::new(std_dev) {
+            Ok(inner) => Ok(Self { inner, mean }),
+            Err(NormalError::TabulationFailure) => Err(WrappedNormalError::TabulationFailure),
+            Err(NormalError::BadStdDev) => Err(WrappedNormalError::BadStdDev),
+        }
+    }
+
+    /// Returns the mean direction `μ`.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    /// Returns the standard deviation `σ` of the unwrapped normal distribution.
+    pub fn std_dev(&self) -> T {
+        self.inner.std_dev()
+    }
+}
+
+impl<T: NormalFloat> Distribution<T> for WrappedNormal<T> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        wrap(self.mean + self.inner.sample(rng))
+    }
+}
+
+// Reduces an angle to (-π, π].
+#[inline]
+fn wrap<T: Float>(x: T) -> T {
+    let two_pi = T::TWO * T::PI;
+
+    x - two_pi * ((x - T::PI) / two_pi).ceil()
+}
@@ -11,12 +11,21 @@ mod small_shape;
 use small_shape::SmallShapeGamma;
 
 /// A floating point type for use with Γ distributions.
+///
+/// This trait also provides the tabulation constants of the distributions
+/// sampled from Γ distributions, namely the log-Γ distribution and, through
+/// their own float traits which have it as a supertrait, the χ², χ and
+/// inverse χ² distributions.
 pub trait GammaFloat: Float {
     #[doc(hidden)]
     type P: Partition<Self>;
     #[doc(hidden)]
     const TOLERANCE: Self;
     #[doc(hidden)]
+    const FAST_TOLERANCE: Self;
+    #[doc(hidden)]
+    const HIGH_TOLERANCE: Self;
+    #[doc(hidden)]
     const LARGE_SHAPE_NORMALIZED_TAIL_POS: Self;
     #[doc(hidden)]
     const SMALL_SHAPE_LEFT_TAIL_ENVELOPE_PROBABILITY: Self;
@@ -30,6 +39,10 @@ impl GammaFloat for f32 {
     #[doc(hidden)]
     const TOLERANCE: Self = 1.0e-1;
     #[doc(hidden)]
+    const FAST_TOLERANCE: Self = 3.0e-1;
+    #[doc(hidden)]
+    const HIGH_TOLERANCE: Self = 1.0e-2;
+    #[doc(hidden)]
     const LARGE_SHAPE_NORMALIZED_TAIL_POS: Self = 3.25;
     #[doc(hidden)]
     const SMALL_SHAPE_LEFT_TAIL_ENVELOPE_PROBABILITY: Self = 0.001;
@@ -43,6 +56,10 @@ impl GammaFloat for f64 {
     #[doc(hidden)]
     const TOLERANCE: Self = 1.0e-6;
     #[doc(hidden)]
+    const FAST_TOLERANCE: Self = 1.0e-3;
+    #[doc(hidden)]
+    const HIGH_TOLERANCE: Self = 1.0e-9;
+    #[doc(hidden)]
     const LARGE_SHAPE_NORMALIZED_TAIL_POS: Self = 3.25;
     #[doc(hidden)]
     const SMALL_SHAPE_LEFT_TAIL_ENVELOPE_PROBABILITY: Self = 0.001;
@@ -50,6 +67,39 @@ impl GammaFloat for f64 {
     const SMALL_SHAPE_RIGHT_TAIL_MAX_PROBABILITY: Self = 0.001;
 }
 
+/// Accuracy of the tabulation of a gamma distribution.
+///
+/// The sampling is exact irrespective of the precision. A higher precision
+/// yields a partition closer to the optimal ETF partition, and thus a higher
+/// acceptance rate at sampling time, at the cost of a slower construction and
+/// of a greater risk of tabulation failure for extreme parameters. The
+/// partition size is determined by the floating point type and is not
+/// affected.
+///
+/// Note that for `f32`, the tolerance cannot be made arbitrarily small due to
+/// the limited resolution of the partition nodes: the tabulation may then fail
+/// with a `High` precision for very large shape parameters (`k≳10⁵`).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GammaPrecision {
+    /// Loose tolerance, for distributions that are frequently constructed.
+    Fast,
+    /// Default tolerance.
+    #[default]
+    Balanced,
+    /// Tight tolerance, for distributions that are sampled at length.
+    High,
+}
+
+impl GammaPrecision {
+    fn tolerance<T: GammaFloat>(self) -> T {
+        match self {
+            GammaPrecision::Fast => T::FAST_TOLERANCE,
+            GammaPrecision::Balanced => T::TOLERANCE,
+            GammaPrecision::High => T::HIGH_TOLERANCE,
+        }
+    }
+}
+
 /// Error type for gamma distribution construction failures.
 #[derive(Error, Debug)]
 pub enum GammaError {
@@ -76,25 +126,92 @@ pub enum GammaError {
 #[derive(Clone)]
 pub struct Gamma<T: GammaFloat> {
     inner: GammaInner<T>,
+    shape: T,
+    scale: T,
 }
 impl<T: GammaFloat> Gamma<T> {
     /// Constructs a gamma distribution with the specified shape and scale.
     pub fn new(shape: T, scale: T) -> Result<Self, GammaError> {
-        if scale <= T::ZERO {
-            return Err(GammaError::BadScale);
+        Self::with_precision(shape, scale, GammaPrecision::default())
+    }
+
+    /// Constructs a gamma distribution with the specified shape and scale,
+    /// using the specified tabulation precision.
+    pub fn with_precision(
+        shape: T,
+        scale: T,
+        precision: GammaPrecision,
+    ) -> Result<Self, GammaError> {
+        Ok(Self {
+            inner: GammaInner::new(shape, scale, precision.tolerance())?,
+            shape,
+            scale,
+        })
+    }
+
+    /// Returns the shape parameter `k`.
+    pub fn shape(&self) -> T {
+        self.shape
+    }
+
+    /// Returns the scale parameter `θ`.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    ///
+    /// The quantile is computed by bisection of the cumulative distribution
+    /// function.
+    pub fn quantile(&self, p: T) -> T {
+        if p <= T::ZERO {
+            return T::ZERO;
         }
-        if shape < T::ONE {
-            if shape <= T::ZERO {
-                return Err(GammaError::BadShape);
+        if p >= T::ONE {
+            return T::INFINITY;
+        }
+
+        // Bracket the quantile, starting from the mean.
+        let mut x_min = T::ZERO;
+        let mut x_max = self.shape;
+        while gamma_cdf(self.shape, x_max) < p {
+            x_min = x_max;
+            x_max = T::TWO * x_max;
+        }
+
+        // Bisect until the relative error is small enough. For very small
+        // shapes, the quantile may underflow, in which case the bisection
+        // stops once the midpoint can no longer be distinguished from the
+        // bounds. The number of iterations is capped as a safeguard, allowing
+        // a bisection down from the largest to the smallest number.
+        const MAX_ITER: u32 = 2_500;
+        let tolerance = T::cast_u32(100) * T::EPSILON;
+        for _ in 0..MAX_ITER {
+            if x_max - x_min <= tolerance * x_max {
+                break;
+            }
+            let x = T::ONE_HALF * (x_min + x_max);
+            if x == x_min || x == x_max {
+                break;
+            }
+            if gamma_cdf(self.shape, x) < p {
+                x_min = x;
+            } else {
+                x_max = x;
             }
-            Ok(Self {
-                inner: GammaInner::SmallShape(SmallShapeGamma::new(shape, scale)?),
-            })
-        } else {
-            Ok(Self {
-                inner: GammaInner::LargeShape(LargeShapeGamma::new(shape, scale)?),
-            })
         }
+
+        self.scale * T::ONE_HALF * (x_min + x_max)
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained by inversion of the quantile function at
+    /// sorted uniform variates. Since the quantile is computed by bisection,
+    /// this is typically slower than sorting regular samples.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        super::sample_sorted(rng, n, |p| self.quantile(p))
     }
 }
 impl<T: GammaFloat> Distribution<T> for Gamma<T> {
@@ -107,8 +224,145 @@ impl<T: GammaFloat> Distribution<T> for Gamma<T> {
     }
 }
 
+/// The distribution of the logarithm of a gamma-distributed variable.
+///
+/// The probability density function is:
+///
+/// ```text
+/// f(y) = exp(k y - exp(y) / θ) / (Γ(k) θ^k)
+/// ```
+///
+/// where the shape parameter `k` and the scale parameter `θ` of the gamma
+/// distribution are strictly positive.
+///
+/// For `k<1`, the gamma distribution is already sampled in log-space, so this
+/// is faster than taking the logarithm of gamma samples and does not lose
+/// accuracy in the left tail.
+#[derive(Clone)]
+pub struct LogGamma<T: GammaFloat> {
+    inner: GammaInner<T>,
+    shape: T,
+    scale: T,
+}
+impl<T: GammaFloat> LogGamma<T> {
+    /// Constructs a log-gamma distribution with the specified shape and scale
+    /// of the gamma distribution.
+    pub fn new(shape: T, scale: T) -> Result<Self, GammaError> {
+        Self::with_precision(shape, scale, GammaPrecision::default())
+    }
+
+    /// Constructs a log-gamma distribution with the specified shape and scale
+    /// of the gamma distribution, using the specified tabulation precision.
+    pub fn with_precision(
+        shape: T,
+        scale: T,
+        precision: GammaPrecision,
+    ) -> Result<Self, GammaError> {
+        Ok(Self {
+            inner: GammaInner::new(shape, scale, precision.tolerance())?,
+            shape,
+            scale,
+        })
+    }
+
+    /// Returns the shape parameter `k` of the gamma distribution.
+    pub fn shape(&self) -> T {
+        self.shape
+    }
+
+    /// Returns the scale parameter `θ` of the gamma distribution.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+}
+impl<T: GammaFloat> Distribution<T> for LogGamma<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        match &self.inner {
+            GammaInner::LargeShape(f) => f.sample(rng).ln(),
+            GammaInner::SmallShape(f) => f.sample_log(rng),
+        }
+    }
+}
+
 #[derive(Clone)]
 enum GammaInner<T: GammaFloat> {
     LargeShape(LargeShapeGamma<T>),
     SmallShape(SmallShapeGamma<T>),
 }
+impl<T: GammaFloat> GammaInner<T> {
+    fn new(shape: T, scale: T, tolerance: T) -> Result<Self, GammaError> {
+        if scale <= T::ZERO {
+            return Err(GammaError::BadScale);
+        }
+        if shape < T::ONE {
+            if shape <= T::ZERO {
+                return Err(GammaError::BadShape);
+            }
+            Ok(GammaInner::SmallShape(SmallShapeGamma::new(
+                shape, scale, tolerance,
+            )?))
+        } else {
+            Ok(GammaInner::LargeShape(LargeShapeGamma::new(
+                shape, scale, tolerance,
+            )?))
+        }
+    }
+}
+
+/// Cumulative distribution function of the gamma distribution with unit scale.
+///
+/// The regularized lower incomplete gamma function `P(k, x)` is evaluated with
+/// its power series for `x < k + 1`, and with the continued fraction of its
+/// complement `Q(k, x)` (modified Lentz's method) otherwise.
+fn gamma_cdf<T: Float>(shape: T, x: T) -> T {
+    const MAX_ITER: u32 = 10_000;
+
+    if x <= T::ZERO {
+        return T::ZERO;
+    }
+    let ln_prefactor = shape * x.ln() - x - shape.ln_gamma();
+
+    if x < shape + T::ONE {
+        let mut a = shape;
+        let mut term = T::ONE / shape;
+        let mut sum = term;
+        for _ in 0..MAX_ITER {
+            a += T::ONE;
+            term *= x / a;
+            sum += term;
+            if term.abs() < sum.abs() * T::EPSILON {
+                break;
+            }
+        }
+
+        sum * ln_prefactor.exp()
+    } else {
+        let tiny = T::from(1.0e-30);
+        let mut b = x + T::ONE - shape;
+        let mut c = T::ONE / tiny;
+        let mut d = T::ONE / b;
+        let mut h = d;
+        for i in 1..MAX_ITER {
+            let i = T::cast_u32(i);
+            let a = -i * (i - shape);
+            b += T::TWO;
+            d = a * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + a / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = T::ONE / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - T::ONE).abs() < T::EPSILON {
+                break;
+            }
+        }
+
+        T::ONE - ln_prefactor.exp() * h
+    }
+}
@@ -28,8 +28,9 @@ pub struct LargeShapeGamma<T: GammaFloat> {
     inner: DistAnyTailed<T::P, T, LargeShapeUnscaledPdf<T>, LargeShapeTail<T>>,
 }
 impl<T: GammaFloat> LargeShapeGamma<T> {
-    /// Constructs a gamma distribution with the specified shape and scale.
-    pub fn new(shape: T, scale: T) -> Result<Self, GammaError> {
+    /// Constructs a gamma distribution with the specified shape and scale,
+    /// tabulated with the specified tolerance.
+    pub fn new(shape: T, scale: T, tolerance: T) -> Result<Self, GammaError> {
         // The left/right tail positions are determined by keeping the tail
         // sampling probability roughly constant, using the Wilson and Hilferty
         // approximation.
@@ -48,8 +49,13 @@ impl<T: GammaFloat> LargeShapeGamma<T> {
                 LargeShapeSingleTail::new_with_area(shape, scale, right_tail_pos);
             (T::ZERO, LargeShapeTail::Single(tail_func), tail_area)
         } else {
-            let (tail_func, tail_area) =
-                LargeShapeDoubleTail::new_with_area(shape, scale, left_tail_pos, right_tail_pos);
+            let (left_tail, left_tail_area) =
+                LargeShapeSingleTail::new_with_area(shape, scale, left_tail_pos);
+            let (right_tail, right_tail_area) =
+                LargeShapeSingleTail::new_with_area(shape, scale, right_tail_pos);
+            let tail_func =
+                LargeShapeDoubleTail::new(left_tail, left_tail_area, right_tail, right_tail_area);
+            let tail_area = tail_func.area();
             (left_tail_pos, LargeShapeTail::Double(tail_func), tail_area)
         };
         let pdf = LargeShapeUnscaledPdf::new(shape, scale);
@@ -57,7 +63,7 @@ impl<T: GammaFloat> LargeShapeGamma<T> {
         let init_nodes = util::midpoint_prepartition(&pdf, left_tail_pos, right_tail_pos, 0);
         let extrema: &[T] = &[scale * (shape - T::ONE)];
         let table =
-            util::newton_tabulation(&pdf, &dpdf, &init_nodes, extrema, T::TOLERANCE, T::ONE, 50)
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, extrema, tolerance, T::ONE, 50)
                 .map_err(|_| GammaError::TabulationFailure)?;
 
         Ok(Self {
@@ -134,7 +140,7 @@ impl<T: Float> LargeShapeUnscaledPdf<T> {
     }
 }
 impl<T: Float> UnivariateFn<T> for LargeShapeUnscaledPdf<T> {
-    #[inline]
+    #[inline(always)]
     fn eval(&self, x: T) -> T {
         let xs = x * self.scaling;
 
@@ -209,34 +215,5 @@ impl<T: Float> TryDistribution<T> for LargeShapeSingleTail<T> {
 }
 
 /// Combined left & right tail envelope of the gamma distribution for `k≥1`.
-#[derive(Copy, Clone, Debug)]
-struct LargeShapeDoubleTail<T> {
-    left: LargeShapeSingleTail<T>,
-    right: LargeShapeSingleTail<T>,
-    left_tail_weight: T,
-}
-impl<T: Float> LargeShapeDoubleTail<T> {
-    fn new_with_area(shape: T, scale: T, left_cut_in: T, right_cut_in: T) -> (Self, T) {
-        let (left, left_area) = LargeShapeSingleTail::new_with_area(shape, scale, left_cut_in);
-        let (right, right_area) = LargeShapeSingleTail::new_with_area(shape, scale, right_cut_in);
-        let area = left_area + right_area;
-        let left_tail_weight = left_area / area;
-        let tail = Self {
-            left,
-            right,
-            left_tail_weight,
-        };
-
-        (tail, area)
-    }
-}
-impl<T: Float> TryDistribution<T> for LargeShapeDoubleTail<T> {
-    #[inline(always)]
-    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
-        if T::gen(rng) < self.left_tail_weight {
-            self.left.try_sample(rng)
-        } else {
-            self.right.try_sample(rng)
-        }
-    }
-}
+type LargeShapeDoubleTail<T> =
+    util::SumEnvelope<T, LargeShapeSingleTail<T>, LargeShapeSingleTail<T>>;
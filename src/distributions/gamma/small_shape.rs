@@ -45,39 +45,54 @@ use rand_core::RngCore;
 ///
 #[derive(Clone)]
 pub struct SmallShapeGamma<T: GammaFloat> {
-    inner: DistAnyTailed<T::P, T, SmallShapeUnscaledPdf<T>, SmallShapeTail<T>>,
+    inner: LogTransformedDist<SmallShapeTransformedDist<T>>,
 }
 impl<T: GammaFloat> SmallShapeGamma<T> {
-    /// Constructs a gamma distribution with the specified shape and scale.
-    pub fn new(shape: T, scale: T) -> Result<Self, GammaError> {
+    /// Constructs a gamma distribution with the specified shape and scale,
+    /// tabulated with the specified tolerance.
+    pub fn new(shape: T, scale: T, tolerance: T) -> Result<Self, GammaError> {
         let left_tail_pos = scale.ln() + T::SMALL_SHAPE_LEFT_TAIL_ENVELOPE_PROBABILITY.ln() / shape;
         let right_tail_pos = (T::ONE
             .max((shape / (T::from(0.8856) * T::SMALL_SHAPE_RIGHT_TAIL_MAX_PROBABILITY)).ln())
             * scale)
             .ln();
 
-        let (tail, tail_area) =
-                SmallShapeTail::new_with_area(shape, scale, left_tail_pos, right_tail_pos);
+        let (left_tail, left_tail_area) =
+            SmallShapeLeftTail::new_with_area(shape, scale, left_tail_pos);
+        let (right_tail, right_tail_area) =
+            SmallShapeRightTail::new_with_area(shape, scale, right_tail_pos);
+        let tail = SmallShapeTail::new(left_tail, left_tail_area, right_tail, right_tail_area);
+        let tail_area = tail.area();
         let pdf = SmallShapeUnscaledPdf::new(shape, scale);
         let dpdf = pdf.derivative();
         let init_nodes = util::midpoint_prepartition(&pdf, left_tail_pos, right_tail_pos, 0);
         let extrema: &[T] = &[(scale * shape).ln()];
         let table =
-            util::newton_tabulation(&pdf, &dpdf, &init_nodes, extrema, T::TOLERANCE, T::ONE, 50)
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, extrema, tolerance, T::ONE, 50)
                 .map_err(|_| GammaError::TabulationFailure)?;
 
         Ok(Self {
-            inner: DistAnyTailed::new(pdf, &table, tail, tail_area),
+            inner: LogTransformedDist::new(DistAnyTailed::new(pdf, &table, tail, tail_area)),
         })
     }
+
+    /// Generates a sample of `ln(x)`.
+    #[inline(always)]
+    pub fn sample_log<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.inner().sample(rng)
+    }
 }
 impl<T: GammaFloat> Distribution<T> for SmallShapeGamma<T> {
     #[inline(always)]
     fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
-        self.inner.sample(rng).exp()
+        self.inner.sample(rng)
     }
 }
 
+/// Distribution of the transformed variable `X = ln(x)` for `k<1`.
+type SmallShapeTransformedDist<T> =
+    DistAnyTailed<<T as GammaFloat>::P, T, SmallShapeUnscaledPdf<T>, SmallShapeTail<T>>;
+
 /// Non-normalized transformed gamma probability distribution function for
 /// `k<1`.
 ///
@@ -105,7 +120,7 @@ impl<T: Float> SmallShapeUnscaledPdf<T> {
     }
 }
 impl<T: Float> UnivariateFn<T> for SmallShapeUnscaledPdf<T> {
-    #[inline]
+    #[inline(always)]
     fn eval(&self, x: T) -> T {
         let exp_x_star = (x - self.ln_scale).exp();
 
@@ -115,37 +130,7 @@ impl<T: Float> UnivariateFn<T> for SmallShapeUnscaledPdf<T> {
 
 /// Combined left & right tail envelope of the transformed gamma distribution
 /// for `k<1`.
-#[derive(Copy, Clone, Debug)]
-struct SmallShapeTail<T> {
-    left: SmallShapeLeftTail<T>,
-    right: SmallShapeRightTail<T>,
-    left_tail_weight: T,
-}
-impl<T: Float> SmallShapeTail<T> {
-    fn new_with_area(shape: T, scale: T, left_cut_in: T, right_cut_in: T) -> (Self, T) {
-        let (left, left_area) = SmallShapeLeftTail::new_with_area(shape, scale, left_cut_in);
-        let (right, right_area) = SmallShapeRightTail::new_with_area(shape, scale, right_cut_in);
-        let area = left_area + right_area;
-        let left_tail_weight = left_area / area;
-        let tail = Self {
-            left,
-            right,
-            left_tail_weight,
-        };
-
-        (tail, area)
-    }
-}
-impl<T: Float> TryDistribution<T> for SmallShapeTail<T> {
-    #[inline(always)]
-    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
-        if T::gen(rng) < self.left_tail_weight {
-            self.left.try_sample(rng)
-        } else {
-            self.right.try_sample(rng)
-        }
-    }
-}
+type SmallShapeTail<T> = util::SumEnvelope<T, SmallShapeLeftTail<T>, SmallShapeRightTail<T>>;
 
 /// Left tail envelope of the transformed gamma distribution for `k<1`.
 ///
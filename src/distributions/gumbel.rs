@@ -58,7 +58,7 @@ pub enum GumbelError {
 /// f(x) = exp(-(z + exp(-z))) / β
 /// ```
 /// where:
-/// ```
+/// ```text
 /// z = (x - μ) / β
 /// ```
 ///
@@ -67,6 +67,8 @@ pub enum GumbelError {
 #[derive(Clone)]
 pub struct Gumbel<T: GumbelFloat> {
     inner: DistAnyTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    location: T,
+    scale: T,
 }
 
 impl<T: GumbelFloat> Gumbel<T> {
@@ -103,8 +105,40 @@ impl<T: GumbelFloat> Gumbel<T> {
 
         Ok(Self {
             inner: DistAnyTailed::new(pdf, &table, tail_func, tail_area),
+            location,
+            scale,
         })
     }
+
+    /// Returns the location parameter.
+    pub fn location(&self) -> T {
+        self.location
+    }
+
+    /// Returns the scale parameter.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Returns the mean `μ + γ β`, where `γ` is the Euler-Mascheroni
+    /// constant.
+    pub fn mean(&self) -> T {
+        self.location + T::EULER_MASCHERONI * self.scale
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    pub fn quantile(&self, p: T) -> T {
+        self.location - self.scale * T::ln(-T::ln(p))
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained in `O(n)` by inversion of the quantile
+    /// function at sorted uniform variates.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        super::sample_sorted(rng, n, |p| self.quantile(p))
+    }
 }
 
 impl<T: GumbelFloat> Distribution<T> for Gumbel<T> {
@@ -132,7 +166,7 @@ impl<T: Float> UnscaledPdf<T> {
 }
 
 impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
-    #[inline]
+    #[inline(always)]
     fn eval(&self, x: T) -> T {
         let minus_z = (self.location - x) * self.inv_scale;
 
@@ -0,0 +1,86 @@
+use super::cauchy::{CauchyFloat, Tail, UnscaledPdf};
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+/// Error type for half-Cauchy distribution construction failures.
+#[derive(Error, Debug)]
+pub enum HalfCauchyError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// The provided scale parameter is not strictly positive.
+    #[error("the scale parameter should be strictly positive")]
+    BadScale,
+}
+
+/// The half-Cauchy distribution.
+///
+/// The probability density function is:
+///
+/// ```text
+/// f(x) = 2𝛾 / (π(x² + 𝛾²))
+/// ```
+///
+/// for `x ≥ 0`, where the scale parameter `𝛾` is strictly positive.
+#[derive(Clone)]
+pub struct HalfCauchy<T: CauchyFloat> {
+    inner: DistAnyTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    scale: T,
+}
+
+impl<T: CauchyFloat> HalfCauchy<T> {
+    /// Constructs a half-Cauchy distribution with the specified scale.
+    pub fn new(scale: T) -> Result<Self, HalfCauchyError> {
+        if scale <= T::ZERO {
+            return Err(HalfCauchyError::BadScale);
+        }
+        let pdf = UnscaledPdf::new(T::ZERO, scale);
+        let square_inv_scale = T::ONE / (scale * scale);
+        let minus_two_square_inv_scale = -T::TWO * square_inv_scale;
+        let dpdf = |x| {
+            let minus_dv = minus_two_square_inv_scale * x;
+            let v = T::ONE + square_inv_scale * x * x;
+
+            minus_dv / (v * v)
+        };
+
+        let tail_position = T::TAIL_POS * scale;
+        let init_nodes = util::midpoint_prepartition(&pdf, T::ZERO, tail_position, 0);
+        let table =
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, &[], T::TOLERANCE, T::ONE, 50)
+                .map_err(|_| HalfCauchyError::TabulationFailure)?;
+        let (tail_func, tail_area) = Tail::new_with_area(T::ZERO, scale);
+        Ok(Self {
+            inner: DistAnyTailed::new(pdf, &table, tail_func, tail_area),
+            scale,
+        })
+    }
+
+    /// Returns the scale parameter.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    pub fn quantile(&self, p: T) -> T {
+        self.scale * T::tan(T::ONE_HALF * T::PI * p)
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained in `O(n)` by inversion of the quantile
+    /// function at sorted uniform variates.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        super::sample_sorted(rng, n, |p| self.quantile(p))
+    }
+}
+
+impl<T: CauchyFloat> Distribution<T> for HalfCauchy<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
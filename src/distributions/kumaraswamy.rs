@@ -0,0 +1,189 @@
+use crate::num::Float;
+use crate::primitives::partition::*;
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+/// A floating point type for use with ETF-based Kumaraswamy distributions.
+pub trait KumaraswamyFloat: Float {
+    #[doc(hidden)]
+    type P: Partition<Self>;
+    #[doc(hidden)]
+    const TOLERANCE: Self;
+}
+
+impl KumaraswamyFloat for f32 {
+    #[doc(hidden)]
+    type P = P256<f32>;
+    #[doc(hidden)]
+    const TOLERANCE: Self = 1.0e-4;
+}
+
+impl KumaraswamyFloat for f64 {
+    #[doc(hidden)]
+    type P = P256<f64>;
+    #[doc(hidden)]
+    const TOLERANCE: Self = 1.0e-6;
+}
+
+/// Error type for Kumaraswamy distribution construction failures.
+#[derive(Error, Debug)]
+pub enum KumaraswamyError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// At least one of the shape parameters is not within the supported range.
+    #[error("the shape parameters are not within the supported range")]
+    BadShape,
+}
+
+/// The Kumaraswamy distribution.
+///
+/// The probability density function is:
+///
+/// ```text
+/// f(x) = a b x^(a - 1) (1 - x^a)^(b - 1)
+/// ```
+///
+/// where `0 ≤ x ≤ 1` and where the shape parameters `a` and `b` are strictly
+/// positive.
+///
+/// The distribution is sampled exactly by inversion of its cumulative
+/// distribution function:
+///
+/// ```text
+/// F(x) = 1 - (1 - x^a)^b
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Kumaraswamy<T> {
+    a: T,
+    b: T,
+    inv_a: T,
+    inv_b: T,
+}
+
+impl<T: Float> Kumaraswamy<T> {
+    /// Constructs a Kumaraswamy distribution with the specified shape
+    /// parameters.
+    pub fn new(a: T, b: T) -> Result<Self, KumaraswamyError> {
+        if !(a > T::ZERO && b > T::ZERO) {
+            return Err(KumaraswamyError::BadShape);
+        }
+
+        Ok(Self {
+            a,
+            b,
+            inv_a: T::ONE / a,
+            inv_b: T::ONE / b,
+        })
+    }
+
+    /// Returns the first shape parameter `a`.
+    pub fn a(&self) -> T {
+        self.a
+    }
+
+    /// Returns the second shape parameter `b`.
+    pub fn b(&self) -> T {
+        self.b
+    }
+}
+
+impl<T: Float> Distribution<T> for Kumaraswamy<T> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        let u = T::ONE - T::gen(rng);
+
+        (T::ONE - u.powf(self.inv_b)).powf(self.inv_a)
+    }
+}
+
+/// The Kumaraswamy distribution sampled with an ETF table.
+///
+/// This is an alternative implementation of [`Kumaraswamy`] which samples the
+/// probability density function over `[0, 1]` with [`DistAny`]. Since the
+/// density must remain finite, both shape parameters must be greater than or
+/// equal to 1.
+///
+/// [`Kumaraswamy`]: struct.Kumaraswamy.html
+/// [`DistAny`]: ../primitives/struct.DistAny.html
+#[derive(Clone)]
+pub struct KumaraswamyETF<T: KumaraswamyFloat> {
+    inner: DistAny<T::P, T, UnscaledPdf<T>>,
+    a: T,
+    b: T,
+}
+
+impl<T: KumaraswamyFloat> KumaraswamyETF<T> {
+    /// Constructs a Kumaraswamy distribution with the specified shape
+    /// parameters, which must both be greater than or equal to 1.
+    pub fn new(a: T, b: T) -> Result<Self, KumaraswamyError> {
+        if !(a >= T::ONE && b >= T::ONE) {
+            return Err(KumaraswamyError::BadShape);
+        }
+        let pdf = UnscaledPdf { a, b };
+        let dpdf = |x: T| {
+            let x_pow_a = x.powf(a);
+
+            x.powf(a - T::TWO)
+                * (T::ONE - x_pow_a).powf(b - T::TWO)
+                * ((a - T::ONE) * (T::ONE - x_pow_a) - a * (b - T::ONE) * x_pow_a)
+        };
+
+        // The mode lies strictly within (0, 1) unless one of the shape
+        // parameters equals 1.
+        let mode = ((a - T::ONE) / (a * b - T::ONE)).powf(T::ONE / a);
+        let extrema: &[T] = if mode > T::ZERO && mode < T::ONE {
+            &[mode]
+        } else {
+            &[]
+        };
+
+        let init_nodes = util::midpoint_prepartition(&pdf, T::ZERO, T::ONE, 0);
+        let table =
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, extrema, T::TOLERANCE, T::ONE, 50)
+                .map_err(|_| KumaraswamyError::TabulationFailure)?;
+
+        Ok(Self {
+            inner: DistAny::new(pdf, &table),
+            a,
+            b,
+        })
+    }
+
+    /// Returns the first shape parameter `a`.
+    pub fn a(&self) -> T {
+        self.a
+    }
+
+    /// Returns the second shape parameter `b`.
+    pub fn b(&self) -> T {
+        self.b
+    }
+}
+
+impl<T: KumaraswamyFloat> Distribution<T> for KumaraswamyETF<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
+
+/// Non-normalized Kumaraswamy probability distribution function.
+///
+/// ```text
+/// f(x) = x^(a - 1) (1 - x^a)^(b - 1)
+/// ```
+#[derive(Copy, Clone, Debug)]
+struct UnscaledPdf<T> {
+    a: T,
+    b: T,
+}
+
+impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
+    #[inline(always)]
+    fn eval(&self, x: T) -> T {
+        x.powf(self.a - T::ONE) * (T::ONE - x.powf(self.a)).powf(self.b - T::ONE)
+    }
+}
@@ -0,0 +1,281 @@
+use crate::num::Float;
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+use super::normal::{Normal, NormalError, NormalFloat};
+
+/// A floating point type for use with log-ratio distributions.
+pub trait LogRatioFloat: NormalFloat {
+    #[doc(hidden)]
+    const TAIL_PROBABILITY: Self;
+}
+
+impl LogRatioFloat for f32 {
+    #[doc(hidden)]
+    const TAIL_PROBABILITY: Self = 1.0e-3;
+}
+
+impl LogRatioFloat for f64 {
+    #[doc(hidden)]
+    const TAIL_PROBABILITY: Self = 1.0e-3;
+}
+
+/// Error type for logit-normal distribution construction failures.
+#[derive(Error, Debug)]
+pub enum LogRatioNormalError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// The provided standard deviation is not strictly positive.
+    #[error("the standard deviation should be strictly positive")]
+    BadStdDev,
+}
+
+/// The logit-normal distribution.
+///
+/// This is the distribution of a variable `p` within `(0, 1)` whose log-odds
+/// `ln(p / (1 - p))` are normally distributed. The probability density
+/// function is:
+///
+/// ```text
+/// f(p) = exp(-½ (logit(p) - μ)² / σ²) / (σ √(2π) p (1 - p))
+/// ```
+///
+/// where `μ` and `σ` are the mean and the strictly positive standard
+/// deviation of the log-odds.
+///
+/// Samples are generated by applying the logistic function to normal samples.
+#[derive(Clone)]
+pub struct LogRatioNormal<T: NormalFloat> {
+    inner: Normal<T>,
+}
+
+impl<T: NormalFloat> LogRatioNormal<T> {
+    /// Constructs a logit-normal distribution with the specified mean and
+    /// standard deviation of the log-odds.
+    pub fn new(mean: T, std_dev: T) -> Result<Self, LogRatioNormalError> {
+        match Normal::new(mean, std_dev) {
+            Ok(inner) => Ok(Self { inner }),
+            Err(NormalError::TabulationFailure) => Err(LogRatioNormalError::TabulationFailure),
+            Err(NormalError::BadStdDev) => Err(LogRatioNormalError::BadStdDev),
+        }
+    }
+
+    /// Returns the mean `μ` of the log-odds.
+    pub fn mean(&self) -> T {
+        self.inner.mean()
+    }
+
+    /// Returns the standard deviation `σ` of the log-odds.
+    pub fn std_dev(&self) -> T {
+        self.inner.std_dev()
+    }
+}
+
+impl<T: NormalFloat> Distribution<T> for LogRatioNormal<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        T::ONE / (T::ONE + (-self.inner.sample(rng)).exp())
+    }
+}
+
+/// Error type for log-odds Beta distribution construction failures.
+#[derive(Error, Debug)]
+pub enum LogOddsBetaError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// One of the provided shape parameters is not strictly positive.
+    #[error("the shape parameters should be strictly positive")]
+    BadShape,
+}
+
+/// The distribution of the log-odds of a Beta variable.
+///
+/// This is the distribution of `x = ln(p / (1 - p))` where `p` follows a
+/// Beta distribution. The probability density function is:
+///
+/// ```text
+/// f(x) = σ(x)^α σ(-x)^β / B(α, β)
+/// ```
+///
+/// where `σ` is the logistic function, `B` is the Beta function and where
+/// the shape parameters `α` and `β` are strictly positive.
+#[derive(Clone)]
+pub struct LogOddsBeta<T: LogRatioFloat> {
+    inner: DistAnyTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    alpha: T,
+    beta: T,
+}
+
+impl<T: LogRatioFloat> LogOddsBeta<T> {
+    /// Constructs a log-odds Beta distribution with the specified shape
+    /// parameters.
+    pub fn new(alpha: T, beta: T) -> Result<Self, LogOddsBetaError> {
+        if !(alpha > T::ZERO && beta > T::ZERO) {
+            return Err(LogOddsBetaError::BadShape);
+        }
+
+        let pdf = UnscaledPdf::new(alpha, beta);
+        let dpdf = |x: T| pdf.eval(x) * (alpha * sigmoid(-x) - beta * sigmoid(x));
+
+        // Place the tails such that each envelope area is a small fraction
+        // of the total area.
+        let mode = (alpha / beta).ln();
+        let ln_beta_fn = alpha.ln_gamma() + beta.ln_gamma() - (alpha + beta).ln_gamma();
+        let ln_tail_area = T::TAIL_PROBABILITY.ln() + ln_beta_fn;
+        let left_tail_pos = ((ln_tail_area + alpha.ln()) / alpha).min(mode);
+        let right_tail_pos = (-(ln_tail_area + beta.ln()) / beta).max(mode);
+
+        let (left_tail, left_tail_area) = LeftTail::new_with_area(&pdf, left_tail_pos);
+        let (right_tail, right_tail_area) = RightTail::new_with_area(&pdf, right_tail_pos);
+        let tail = Tail::new(left_tail, left_tail_area, right_tail, right_tail_area);
+        let tail_area = tail.area();
+
+        let init_nodes = util::midpoint_prepartition(&pdf, left_tail_pos, right_tail_pos, 0);
+        let table =
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, &[mode], T::TOLERANCE, T::ONE, 50)
+                .map_err(|_| LogOddsBetaError::TabulationFailure)?;
+
+        Ok(Self {
+            inner: DistAnyTailed::new(pdf, &table, tail, tail_area),
+            alpha,
+            beta,
+        })
+    }
+
+    /// Returns the shape parameter `α`.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+
+    /// Returns the shape parameter `β`.
+    pub fn beta(&self) -> T {
+        self.beta
+    }
+}
+
+impl<T: LogRatioFloat> Distribution<T> for LogOddsBeta<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
+
+// Logistic function.
+#[inline(always)]
+fn sigmoid<T: Float>(x: T) -> T {
+    T::ONE / (T::ONE + (-x).exp())
+}
+
+// Logarithm of the logistic function, computed without overflow.
+#[inline(always)]
+fn ln_sigmoid<T: Float>(x: T) -> T {
+    -((T::ONE + (-x.abs()).exp()).ln() + (-x).max(T::ZERO))
+}
+
+/// Non-normalized log-odds Beta probability distribution function, scaled to
+/// 1 at the mode.
+#[derive(Copy, Clone, Debug)]
+struct UnscaledPdf<T> {
+    alpha: T,
+    beta: T,
+    ln_max: T,
+}
+
+impl<T: Float> UnscaledPdf<T> {
+    fn new(alpha: T, beta: T) -> Self {
+        let sum = alpha + beta;
+
+        Self {
+            alpha,
+            beta,
+            ln_max: alpha * (alpha / sum).ln() + beta * (beta / sum).ln(),
+        }
+    }
+}
+
+impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
+    #[inline(always)]
+    fn eval(&self, x: T) -> T {
+        (self.alpha * ln_sigmoid(x) + self.beta * ln_sigmoid(-x) - self.ln_max).exp()
+    }
+}
+
+/// Envelope of the left tail.
+///
+/// The envelope function is `exp(α x - ln_max)` with `x ≤ xl`, which is
+/// sampled exactly and then rejected with probability `1 - σ(-x)^(α+β)`.
+#[derive(Copy, Clone, Debug)]
+struct LeftTail<T> {
+    cut_in: T,
+    alpha: T,
+    sum: T, // alpha + beta
+}
+
+impl<T: Float> LeftTail<T> {
+    fn new_with_area(pdf: &UnscaledPdf<T>, cut_in: T) -> (Self, T) {
+        let tail = Self {
+            cut_in,
+            alpha: pdf.alpha,
+            sum: pdf.alpha + pdf.beta,
+        };
+        let area = (pdf.alpha * cut_in - pdf.ln_max).exp() / pdf.alpha;
+
+        (tail, area)
+    }
+}
+
+impl<T: Float> TryDistribution<T> for LeftTail<T> {
+    #[inline(always)]
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let x = self.cut_in + (T::ONE - T::gen(rng)).ln() / self.alpha;
+
+        if self.sum * ln_sigmoid(-x) > T::gen(rng).ln() {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+/// Envelope of the right tail.
+///
+/// The envelope function is `exp(-β x - ln_max)` with `x ≥ xr`, which is
+/// sampled exactly and then rejected with probability `1 - σ(x)^(α+β)`.
+#[derive(Copy, Clone, Debug)]
+struct RightTail<T> {
+    cut_in: T,
+    beta: T,
+    sum: T, // alpha + beta
+}
+
+impl<T: Float> RightTail<T> {
+    fn new_with_area(pdf: &UnscaledPdf<T>, cut_in: T) -> (Self, T) {
+        let tail = Self {
+            cut_in,
+            beta: pdf.beta,
+            sum: pdf.alpha + pdf.beta,
+        };
+        let area = (-pdf.beta * cut_in - pdf.ln_max).exp() / pdf.beta;
+
+        (tail, area)
+    }
+}
+
+impl<T: Float> TryDistribution<T> for RightTail<T> {
+    #[inline(always)]
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let x = self.cut_in - (T::ONE - T::gen(rng)).ln() / self.beta;
+
+        if self.sum * ln_sigmoid(x) > T::gen(rng).ln() {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+type Tail<T> = util::SumEnvelope<T, LeftTail<T>, RightTail<T>>;
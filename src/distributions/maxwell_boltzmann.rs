@@ -0,0 +1,188 @@
+use crate::num::Float;
+use crate::primitives::partition::*;
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+/// A floating point type for use with Maxwell-Boltzmann distributions.
+pub trait MaxwellBoltzmannFloat: Float {
+    #[doc(hidden)]
+    type P: Partition<Self>;
+    #[doc(hidden)]
+    const TOLERANCE: Self;
+    #[doc(hidden)]
+    const TAIL_POS: Self;
+}
+
+impl MaxwellBoltzmannFloat for f32 {
+    #[doc(hidden)]
+    type P = P256<f32>;
+    #[doc(hidden)]
+    const TOLERANCE: Self = 1.0e-4;
+    #[doc(hidden)]
+    const TAIL_POS: Self = 4.0;
+}
+
+impl MaxwellBoltzmannFloat for f64 {
+    #[doc(hidden)]
+    type P = P256<f64>;
+    #[doc(hidden)]
+    const TOLERANCE: Self = 1.0e-6;
+    #[doc(hidden)]
+    const TAIL_POS: Self = 4.0;
+}
+
+/// Error type for Maxwell-Boltzmann distribution construction failures.
+#[derive(Error, Debug)]
+pub enum MaxwellBoltzmannError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// The provided scale parameter is not strictly positive.
+    #[error("the scale parameter should be strictly positive")]
+    BadScale,
+}
+
+/// The Maxwell-Boltzmann distribution.
+///
+/// The probability density function is:
+///
+/// ```text
+/// f(v) = √(2/π) v² exp(-½ v² / a²) / a³
+/// ```
+///
+/// where `v ≥ 0` and where the scale parameter `a` is strictly positive. For
+/// the speed of particles of mass `m` at temperature `T`, `a = √(kT/m)`.
+///
+/// The right tail is sampled using the fact that `u = v² / (2a²)` follows a
+/// gamma distribution with shape `3/2`. Beyond the cut-in position `uₜ`, the
+/// concavity of `ln(√u)` yields the envelope:
+///
+/// ```text
+/// √u exp(-u) ≤ √uₜ exp(-u + (u - uₜ) / (2uₜ))
+/// ```
+///
+/// which is a shifted exponential distribution in `u`, or equivalently a
+/// Weibull distribution with shape 2 in `v`.
+#[derive(Clone)]
+pub struct MaxwellBoltzmann<T: MaxwellBoltzmannFloat> {
+    inner: DistAnyTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    scale: T,
+}
+
+impl<T: MaxwellBoltzmannFloat> MaxwellBoltzmann<T> {
+    /// Constructs a Maxwell-Boltzmann distribution with the specified scale.
+    pub fn new(scale: T) -> Result<Self, MaxwellBoltzmannError> {
+        if scale <= T::ZERO {
+            return Err(MaxwellBoltzmannError::BadScale);
+        }
+        let pdf = UnscaledPdf::new(scale);
+        let alpha = T::ONE_HALF / (scale * scale);
+        let dpdf = move |v: T| {
+            let u = alpha * v * v;
+
+            T::TWO * alpha * v * (T::ONE - u) * (T::ONE - u).exp()
+        };
+
+        let mode = T::TWO.sqrt() * scale;
+        let tail_position = T::TAIL_POS * scale;
+        let init_nodes = util::midpoint_prepartition(&pdf, T::ZERO, tail_position, 0);
+        let table =
+            util::newton_tabulation(&pdf, &dpdf, &init_nodes, &[mode], T::TOLERANCE, T::ONE, 50)
+                .map_err(|_| MaxwellBoltzmannError::TabulationFailure)?;
+        let (tail_func, tail_area) = Tail::new_with_area(scale);
+
+        Ok(Self {
+            inner: DistAnyTailed::new(pdf, &table, tail_func, tail_area),
+            scale,
+        })
+    }
+
+    /// Returns the scale parameter `a`.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+}
+
+impl<T: MaxwellBoltzmannFloat> Distribution<T> for MaxwellBoltzmann<T> {
+    #[inline(always)]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
+
+/// Maxwell-Boltzmann probability distribution function normalized by its
+/// maximum.
+///
+/// ```text
+/// f(v) = u exp(1 - u)   with u = v² / (2a²)
+/// ```
+#[derive(Copy, Clone, Debug)]
+struct UnscaledPdf<T> {
+    alpha: T, // 1/(2 a^2)
+}
+
+impl<T: Float> UnscaledPdf<T> {
+    fn new(scale: T) -> Self {
+        Self {
+            alpha: T::ONE_HALF / (scale * scale),
+        }
+    }
+}
+
+impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
+    #[inline(always)]
+    fn eval(&self, v: T) -> T {
+        let u = self.alpha * v * v;
+
+        u * (T::ONE - u).exp()
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Tail<T> {
+    cut_in: T,          // uₜ
+    inv_rate: T,        // 1 / (1 - 1 / (2uₜ))
+    half_inv_cut_in: T, // 1 / (2uₜ)
+    sqrt_two_scale: T,
+}
+
+impl<T: MaxwellBoltzmannFloat> Tail<T> {
+    fn new_with_area(scale: T) -> (Self, T) {
+        let cut_in = T::ONE_HALF * T::TAIL_POS * T::TAIL_POS;
+        let half_inv_cut_in = T::ONE_HALF / cut_in;
+
+        let tail = Self {
+            cut_in,
+            inv_rate: T::ONE / (T::ONE - half_inv_cut_in),
+            half_inv_cut_in,
+            sqrt_two_scale: T::TWO.sqrt() * scale,
+        };
+
+        // The area under `u exp(1 - u)` beyond `vₜ` is `a e Γ(3/2, uₜ) / √2`,
+        // where the upper incomplete gamma function is:
+        // Γ(3/2, uₜ) = √uₜ exp(-uₜ) + ½ √π erfc(√uₜ).
+        let sqrt_cut_in = cut_in.sqrt();
+        let upper_gamma =
+            sqrt_cut_in * (-cut_in).exp() + T::ONE_HALF * T::PI.sqrt() * sqrt_cut_in.erfc();
+        let area = scale * T::ONE.exp() * upper_gamma / T::TWO.sqrt();
+
+        (tail, area)
+    }
+}
+
+impl<T: Float> TryDistribution<T> for Tail<T> {
+    #[inline(always)]
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let u = self.cut_in - self.inv_rate * (T::ONE - T::gen(rng)).ln();
+
+        if T::ONE_HALF * (u / self.cut_in).ln() - (u - self.cut_in) * self.half_inv_cut_in
+            >= (T::ONE - T::gen(rng)).ln()
+        {
+            Some(self.sqrt_two_scale * u.sqrt())
+        } else {
+            None
+        }
+    }
+}
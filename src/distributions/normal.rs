@@ -3,6 +3,7 @@ use crate::primitives::partition::*;
 use crate::primitives::*;
 
 use rand_core::RngCore;
+use std::fmt;
 use thiserror::Error;
 
 /// A floating point type for use with normal distributions.
@@ -57,6 +58,8 @@ pub enum NormalError {
 #[derive(Clone)]
 pub struct Normal<T: NormalFloat> {
     inner: DistSymmetricTailed<T::P, T, UnscaledPdf<T>, Tail<T>>,
+    mean: T,
+    std_dev: T,
 }
 
 impl<T: NormalFloat> Normal<T> {
@@ -66,18 +69,57 @@ impl<T: NormalFloat> Normal<T> {
             return Err(NormalError::BadStdDev);
         }
         let pdf = UnscaledPdf::new(mean, std_dev);
-        let two_alpha = -T::ONE / (std_dev * std_dev);
-        let alpha = T::ONE_HALF * two_alpha;
-        let dpdf = |x: T| {
-            let dx = x - mean;
-            dx * two_alpha * (dx * dx * alpha).exp()
-        };
-        let (table, tail_func, tail_area) = normal_parts(mean, std_dev, pdf, dpdf)?;
+        let (table, tail_func, tail_area) = unscaled_normal_parts(mean, std_dev)?;
 
         Ok(Self {
             inner: DistSymmetricTailed::new(mean, pdf, &table, tail_func, tail_area),
+            mean,
+            std_dev,
         })
     }
+
+    /// Returns the initialization table of the distribution.
+    ///
+    /// The table is computed anew, which is deterministic: the same parameters
+    /// always produce bit-identical tables.
+    #[cfg(feature = "debug_utils")]
+    pub fn init_table(&self) -> InitTable<T::P, T> {
+        // The parameters were already checked at construction.
+        unscaled_normal_parts(self.mean, self.std_dev).unwrap().0
+    }
+
+    /// Returns the mean `μ`.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    /// Returns the standard deviation `σ`.
+    pub fn std_dev(&self) -> T {
+        self.std_dev
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    pub fn quantile(&self, p: T) -> T {
+        self.mean + self.std_dev * standard_quantile(p)
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained in `O(n)` by inversion of the quantile
+    /// function at sorted uniform variates.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        super::sample_sorted(rng, n, |p| self.quantile(p))
+    }
+}
+
+impl<T: NormalFloat> fmt::Debug for Normal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Normal")
+            .field("mean", &self.mean)
+            .field("std_dev", &self.std_dev)
+            .finish()
+    }
 }
 
 impl<T: NormalFloat> Distribution<T> for Normal<T> {
@@ -102,6 +144,7 @@ impl<T: NormalFloat> Distribution<T> for Normal<T> {
 #[derive(Clone)]
 pub struct CentralNormal<T: NormalFloat> {
     inner: DistCentralTailed<T::P, T, UnscaledCentralPdf<T>, Tail<T>>,
+    std_dev: T,
 }
 
 impl<T: NormalFloat> CentralNormal<T> {
@@ -118,8 +161,28 @@ impl<T: NormalFloat> CentralNormal<T> {
         let (table, tail_func, tail_area) = normal_parts(T::ZERO, std_dev, pdf, dpdf)?;
         Ok(Self {
             inner: DistCentralTailed::new(pdf, &table, tail_func, tail_area),
+            std_dev,
         })
     }
+
+    /// Returns the standard deviation `σ`.
+    pub fn std_dev(&self) -> T {
+        self.std_dev
+    }
+
+    /// Returns the quantile of order `p`, where `p` lies within `[0, 1]`.
+    pub fn quantile(&self, p: T) -> T {
+        self.std_dev * standard_quantile(p)
+    }
+
+    /// Generates `n` samples in ascending order.
+    ///
+    /// The samples are obtained in `O(n)` by inversion of the quantile
+    /// function at sorted uniform variates.
+    #[cfg(feature = "sorted_samples")]
+    pub fn sample_sorted<R: RngCore + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<T> {
+        super::sample_sorted(rng, n, |p| self.quantile(p))
+    }
 }
 
 impl<T: NormalFloat> Distribution<T> for CentralNormal<T> {
@@ -147,7 +210,7 @@ impl<T: Float> UnscaledPdf<T> {
 }
 
 impl<T: Float> UnivariateFn<T> for UnscaledPdf<T> {
-    #[inline]
+    #[inline(always)]
     fn eval(&self, x: T) -> T {
         let dx = x - self.mean;
 
@@ -169,7 +232,7 @@ impl<T: Float> UnscaledCentralPdf<T> {
 }
 
 impl<T: Float> UnivariateFn<T> for UnscaledCentralPdf<T> {
-    #[inline]
+    #[inline(always)]
     fn eval(&self, x: T) -> T {
         (self.alpha * x * x).exp()
     }
@@ -198,7 +261,7 @@ impl<T: NormalFloat> Tail<T> {
 }
 
 impl<T: Float> TryDistribution<T> for Tail<T> {
-    #[inline]
+    #[inline(always)]
     fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
         loop {
             let x = (T::ONE - T::gen(rng)).ln() * self.a_x;
@@ -210,7 +273,41 @@ impl<T: Float> TryDistribution<T> for Tail<T> {
     }
 }
 
+// Quantile of the standard normal distribution.
+//
+// The tails are computed with the inverse complementary error function since
+// `2p - 1` would cancel catastrophically for `p` close to 0 or 1.
+fn standard_quantile<T: Float>(p: T) -> T {
+    let quarter = T::from(0.25);
+    let y = if p < quarter {
+        -(T::TWO * p).erfcinv()
+    } else if p > T::ONE - quarter {
+        (T::TWO * (T::ONE - p)).erfcinv()
+    } else {
+        (T::TWO * p - T::ONE).erfinv()
+    };
+
+    T::TWO.sqrt() * y
+}
+
 #[allow(clippy::type_complexity)]
+// Computes the table and the tail of a normal distribution with unscaled
+// probability density function.
+fn unscaled_normal_parts<T: NormalFloat>(
+    mean: T,
+    std_dev: T,
+) -> Result<(InitTable<T::P, T>, Tail<T>, T), NormalError> {
+    let pdf = UnscaledPdf::new(mean, std_dev);
+    let two_alpha = -T::ONE / (std_dev * std_dev);
+    let alpha = T::ONE_HALF * two_alpha;
+    let dpdf = |x: T| {
+        let dx = x - mean;
+        dx * two_alpha * (dx * dx * alpha).exp()
+    };
+
+    normal_parts(mean, std_dev, pdf, dpdf)
+}
+
 fn normal_parts<T: NormalFloat, F: UnivariateFn<T>, DF: UnivariateFn<T>>(
     mean: T,
     std_dev: T,
@@ -0,0 +1,440 @@
+use super::{Cauchy, CauchyError, CauchyFloat, CentralNormal, NormalError, NormalFloat};
+use crate::primitives::partition::*;
+use crate::primitives::*;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+/// A floating point type for use with symmetric α-stable distributions.
+pub trait StableFloat: NormalFloat + CauchyFloat {
+    #[doc(hidden)]
+    type P: Partition<Self>;
+    #[doc(hidden)]
+    const TOLERANCE: Self;
+    #[doc(hidden)]
+    const QUADRATURE_TOLERANCE: Self;
+    #[doc(hidden)]
+    const TAIL_PROBABILITY: Self;
+    #[doc(hidden)]
+    const MIN_ALPHA: Self;
+}
+
+impl StableFloat for f32 {
+    #[doc(hidden)]
+    type P = P512<f32>;
+    #[doc(hidden)]
+    const TOLERANCE: Self = 1.0e-3;
+    #[doc(hidden)]
+    const QUADRATURE_TOLERANCE: Self = 1.0e-5;
+    #[doc(hidden)]
+    const TAIL_PROBABILITY: Self = 1.0e-3;
+    #[doc(hidden)]
+    const MIN_ALPHA: Self = 0.3;
+}
+
+impl StableFloat for f64 {
+    #[doc(hidden)]
+    type P = P512<f64>;
+    #[doc(hidden)]
+    const TOLERANCE: Self = 1.0e-6;
+    #[doc(hidden)]
+    const QUADRATURE_TOLERANCE: Self = 1.0e-10;
+    #[doc(hidden)]
+    const TAIL_PROBABILITY: Self = 1.0e-3;
+    #[doc(hidden)]
+    const MIN_ALPHA: Self = 0.3;
+}
+
+/// Error type for symmetric α-stable distribution construction failures.
+#[derive(Error, Debug)]
+pub enum StableError {
+    /// The ETF table could not be computed for the provided distribution parameters.
+    #[error("could not compute an ETF table for the provided distribution parameters")]
+    TabulationFailure,
+    /// The provided stability parameter is not within [0.3, 2].
+    #[error("the stability parameter should be within [0.3, 2]")]
+    BadAlpha,
+    /// The provided scale parameter is not strictly positive.
+    #[error("the scale parameter should be strictly positive")]
+    BadScale,
+}
+
+/// The symmetric α-stable distribution.
+///
+/// The characteristic function is:
+///
+/// ```text
+/// φ(t) = exp(-|c t|^α)
+/// ```
+///
+/// where the stability parameter `α` lies within `(0, 2]` and where the scale
+/// parameter `c` is strictly positive. Only stability parameters within
+/// `[0.3, 2]` are supported: below, the tabulation becomes prohibitively slow
+/// and eventually fails.
+///
+/// The distribution is sampled as a [`CentralNormal`] distribution with
+/// standard deviation `σ = √2 c` for `α=2` and as a [`Cauchy`] distribution
+/// for `α=1`. Otherwise, the probability density function is evaluated by
+/// numerical quadrature of Zolotarev's integral representation and the tails,
+/// which decay as `|x|^-(α+1)`, are sampled with a Pareto envelope.
+///
+/// The construction is comparatively slow, and so is the rejection step of the
+/// sampling algorithm since it requires the evaluation of the probability
+/// density function. Convergence of the tabulation is not guaranteed for
+/// stability parameters very close to 0 or 1.
+///
+/// [`CentralNormal`]: struct.CentralNormal.html
+/// [`Cauchy`]: struct.Cauchy.html
+#[derive(Clone)]
+pub struct SymmetricStable<T: StableFloat> {
+    inner: StableInner<T>,
+    alpha: T,
+    scale: T,
+}
+
+impl<T: StableFloat> SymmetricStable<T> {
+    /// Constructs a symmetric α-stable distribution with the specified
+    /// stability parameter and scale.
+    pub fn new(alpha: T, scale: T) -> Result<Self, StableError> {
+        if !(alpha >= T::MIN_ALPHA && alpha <= T::TWO) {
+            return Err(StableError::BadAlpha);
+        }
+        if scale <= T::ZERO {
+            return Err(StableError::BadScale);
+        }
+
+        let inner = if alpha == T::TWO {
+            StableInner::Normal(
+                CentralNormal::new(T::TWO.sqrt() * scale).map_err(|e| match e {
+                    NormalError::TabulationFailure => StableError::TabulationFailure,
+                    NormalError::BadStdDev => StableError::BadScale,
+                })?,
+            )
+        } else if alpha == T::ONE {
+            StableInner::Cauchy(Cauchy::new(T::ZERO, scale).map_err(|e| match e {
+                CauchyError::TabulationFailure => StableError::TabulationFailure,
+                CauchyError::BadScale => StableError::BadScale,
+            })?)
+        } else {
+            StableInner::Etf(stable_dist(alpha, scale)?)
+        };
+
+        Ok(Self {
+            inner,
+            alpha,
+            scale,
+        })
+    }
+
+    /// Returns the stability parameter `α`.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+
+    /// Returns the scale parameter `c`.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+}
+
+impl<T: StableFloat> Distribution<T> for SymmetricStable<T> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        match &self.inner {
+            StableInner::Normal(d) => d.sample(rng),
+            StableInner::Cauchy(d) => d.sample(rng),
+            StableInner::Etf(d) => d.sample(rng),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum StableInner<T: StableFloat> {
+    Normal(CentralNormal<T>),
+    Cauchy(Cauchy<T>),
+    Etf(StableDist<T>),
+}
+
+/// ETF distribution for `α≠1` and `α≠2`.
+type StableDist<T> = DistCentralTailed<<T as StableFloat>::P, T, UnscaledPdf<T>, Tail<T>>;
+
+/// Tabulates a symmetric α-stable distribution with `α≠1` and `α≠2`.
+fn stable_dist<T: StableFloat>(alpha: T, scale: T) -> Result<StableDist<T>, StableError> {
+    let pdf = UnscaledPdf {
+        standard: StandardPdf::new(alpha),
+        inv_scale: T::ONE / scale,
+    };
+    let step = T::QUADRATURE_TOLERANCE.sqrt();
+    let dpdf = |x: T| {
+        let dx = step * (x + scale);
+
+        (pdf.eval(x + dx) - pdf.eval(x - dx)) / (T::TWO * dx)
+    };
+
+    let (tail_func, tail_area) = Tail::new_with_area(pdf.standard, scale);
+    let tail_position = scale * tail_func.cut_in;
+
+    // Since the tail position may be several orders of magnitude larger than
+    // the scale, the initial partition evenly divides the area under the
+    // density of `u = ln(1 + x/c)` rather than that of `x`.
+    let pdf_u = |u: T| {
+        let exp_u = u.exp();
+
+        pdf.eval(scale * (exp_u - T::ONE)) * exp_u
+    };
+    let mut init_nodes =
+        util::midpoint_prepartition(&pdf_u, T::ZERO, (T::ONE + tail_func.cut_in).ln(), 0);
+    for i in 1..<T as StableFloat>::P::SIZE {
+        init_nodes[i] = scale * (init_nodes[i].exp() - T::ONE);
+    }
+    init_nodes[<T as StableFloat>::P::SIZE] = tail_position;
+    let table = util::newton_tabulation(
+        &pdf,
+        &dpdf,
+        &init_nodes,
+        &[],
+        <T as StableFloat>::TOLERANCE,
+        T::ONE,
+        50,
+    )
+    .map_err(|_| StableError::TabulationFailure)?;
+
+    Ok(DistCentralTailed::new(pdf, &table, tail_func, tail_area))
+}
+
+/// Probability density function of the standard (`c=1`) symmetric α-stable
+/// distribution with `α≠1` and `α≠2`.
+///
+/// Near the origin, the density is computed with the Taylor expansion:
+///
+/// ```text
+/// f(z) ≈ (Γ(1/α) - Γ(3/α) z² / 2) / (πα)
+/// ```
+///
+/// and far in the tail with the first terms of the asymptotic expansion:
+///
+/// ```text
+/// f(z) ≈ Σₖ (-1)^(k+1) Γ(αk + 1) sin(kπα/2) / (π k! z^(αk+1))
+/// ```
+///
+/// Elsewhere, the density is computed with Zolotarev's integral representation:
+///
+/// ```text
+/// f(z) = α / (π|α - 1| z) ∫₀^(π/2) g(θ) exp(-g(θ)) dθ   with z > 0
+/// ```
+///
+/// where:
+///
+/// ```text
+/// g(θ) = z^(α/(α - 1)) (cos θ / sin αθ)^(α/(α - 1)) cos((α - 1)θ) / cos θ
+/// ```
+///
+/// Since `g` is monotonic, the integral is split at the peak of the integrand
+/// where `g(θ)=1`.
+#[derive(Copy, Clone, Debug)]
+struct StandardPdf<T> {
+    alpha: T,
+    exponent: T,  // α/(α - 1)
+    prefactor: T, // α/(π|α - 1|)
+    c0: T,        // Γ(1/α)/(πα)
+    c2: T,        // Γ(3/α)/(2πα)
+    z_small: T,
+    a: [T; 4], // asymptotic expansion coefficients
+    z_large: T,
+}
+
+impl<T: StableFloat> StandardPdf<T> {
+    fn new(alpha: T) -> Self {
+        let inv_alpha = T::ONE / alpha;
+        let pi_alpha = T::PI * alpha;
+        let m = alpha - T::ONE;
+
+        // The Taylor expansion is used where the quartic term is negligible.
+        let ln_z_small = T::from(0.25)
+            * (T::EPSILON.ln() + inv_alpha.ln_gamma() + T::from(24.0).ln()
+                - (T::from(5.0) * inv_alpha).ln_gamma());
+
+        let mut a = [T::ZERO; 4];
+        let mut ln_factorial = T::ZERO;
+        for (k, a_k) in a.iter_mut().enumerate() {
+            let k = T::cast_usize(k + 1);
+            ln_factorial += k.ln();
+            *a_k = ((alpha * k + T::ONE).ln_gamma() - ln_factorial).exp()
+                * (T::ONE_HALF * T::PI * alpha * k).sin()
+                / T::PI;
+        }
+        a[1] = -a[1];
+        a[3] = -a[3];
+
+        // The asymptotic expansion is used where the first omitted term,
+        // bounded relatively to the leading term, is negligible.
+        let ln_z_large = ((T::from(5.0) * alpha + T::ONE).ln_gamma()
+            - T::from(120.0).ln()
+            - (alpha + T::ONE).ln_gamma()
+            - (T::ONE_HALF * T::PI * alpha).sin().ln()
+            - T::EPSILON.ln())
+            / (T::from(4.0) * alpha);
+
+        Self {
+            alpha,
+            exponent: alpha / m,
+            prefactor: alpha / (T::PI * m.abs()),
+            c0: inv_alpha.ln_gamma().exp() / pi_alpha,
+            c2: (T::from(3.0) * inv_alpha).ln_gamma().exp() / (T::TWO * pi_alpha),
+            z_small: ln_z_small.exp(),
+            a,
+            z_large: ln_z_large.exp(),
+        }
+    }
+
+    /// Logarithm of `g(θ)` for `ln_z = ln(z)`.
+    #[inline]
+    fn ln_g(&self, ln_z: T, theta: T) -> T {
+        let ln_cos = theta.cos().ln();
+
+        self.exponent * (ln_z + ln_cos - (self.alpha * theta).sin().ln())
+            + ((self.alpha - T::ONE) * theta).cos().ln()
+            - ln_cos
+    }
+
+    fn zolotarev(&self, z: T) -> T {
+        const BISECTION_ITER: u32 = 40;
+
+        let ln_z = z.ln();
+        let half_pi = T::ONE_HALF * T::PI;
+
+        // `g` is increasing for `α<1` and decreasing for `α>1`.
+        let increasing = self.alpha < T::ONE;
+        let mut theta_min = T::ZERO;
+        let mut theta_max = half_pi;
+        for _ in 0..BISECTION_ITER {
+            let theta = T::ONE_HALF * (theta_min + theta_max);
+            if (self.ln_g(ln_z, theta) < T::ZERO) == increasing {
+                theta_min = theta;
+            } else {
+                theta_max = theta;
+            }
+        }
+        let theta_peak = T::ONE_HALF * (theta_min + theta_max);
+
+        let integrand = |theta: T| {
+            // The integrand vanishes at both ends of the interval, where `ln_g`
+            // may evaluate to an infinity or, due to rounding, to NaN.
+            let ln_g = self.ln_g(ln_z, theta);
+            if ln_g.is_nan() || ln_g == T::INFINITY {
+                return T::ZERO;
+            }
+
+            (ln_g - ln_g.exp()).exp()
+        };
+        let integral = |theta0: T, theta1: T| {
+            util::pdf_area_adaptive(&integrand, theta0, theta1, T::QUADRATURE_TOLERANCE)
+                .unwrap_or_else(|_| util::pdf_area(&integrand, theta0, theta1, 1000))
+        };
+
+        self.prefactor / z * (integral(T::ZERO, theta_peak) + integral(theta_peak, half_pi))
+    }
+}
+
+impl<T: StableFloat> UnivariateFn<T> for StandardPdf<T> {
+    fn eval(&self, z: T) -> T {
+        let z = z.abs();
+        if z < self.z_small {
+            return self.c0 - self.c2 * z * z;
+        }
+        if z > self.z_large {
+            let w = z.powf(-self.alpha);
+
+            return w * (self.a[0] + w * (self.a[1] + w * (self.a[2] + w * self.a[3]))) / z;
+        }
+
+        self.zolotarev(z)
+    }
+}
+
+/// Non-normalized probability density function of the symmetric α-stable
+/// distribution with arbitrary scale.
+#[derive(Copy, Clone, Debug)]
+struct UnscaledPdf<T> {
+    standard: StandardPdf<T>,
+    inv_scale: T,
+}
+
+impl<T: StableFloat> UnivariateFn<T> for UnscaledPdf<T> {
+    #[inline]
+    fn eval(&self, x: T) -> T {
+        self.standard.eval(x * self.inv_scale)
+    }
+}
+
+/// Pareto tail envelope of the symmetric α-stable distribution.
+///
+/// The envelope function for the standard distribution is:
+///
+/// ```text
+/// fₜ(z) = A z^-(α+1)   with z ≥ zₜ
+/// ```
+///
+/// where `A` is the larger of the asymptotic prefactor of the density,
+/// `Γ(α+1) sin(πα/2) / π`, and of `f(zₜ) zₜ^(α+1)`. This is indeed an upper
+/// bound of the density since, beyond the bulk of the distribution,
+/// `f(z) z^(α+1)` increases towards its asymptote for `α<1` and decreases
+/// towards it for `α>1`.
+///
+/// As `α→2`, the asymptotic prefactor vanishes and the density approaches
+/// that of a normal distribution with variance 2, whose bulk extends much
+/// further than the position derived from the asymptotic prefactor. The cut-in
+/// position is therefore never lower than the position beyond which the
+/// two-sided tail probability of this normal distribution is bounded by
+/// `TAIL_PROBABILITY`, using the Chernoff bound `P(|z|>zₜ) ≤ exp(-zₜ²/4)`.
+#[derive(Copy, Clone, Debug)]
+struct Tail<T> {
+    pdf: StandardPdf<T>,
+    cut_in: T,
+    a: T,
+    minus_inv_alpha: T,
+    minus_alpha_minus_one: T,
+    scale: T,
+}
+
+impl<T: StableFloat> Tail<T> {
+    fn new_with_area(pdf: StandardPdf<T>, scale: T) -> (Self, T) {
+        let alpha = pdf.alpha;
+        let asymptotic_a =
+            (alpha + T::ONE).ln_gamma().exp() * (T::ONE_HALF * T::PI * alpha).sin() / T::PI;
+
+        // The tail position is chosen such that the probability of sampling
+        // either tail is approximately `TAIL_PROBABILITY`, but it is kept
+        // beyond the bulk of the normal limit for `α→2`.
+        let pareto_cut_in =
+            (T::TWO * asymptotic_a / (alpha * T::TAIL_PROBABILITY)).powf(T::ONE / alpha);
+        let normal_cut_in = T::TWO * (-T::TAIL_PROBABILITY.ln()).sqrt();
+        let cut_in = pareto_cut_in.max(normal_cut_in);
+        let a = asymptotic_a.max(pdf.eval(cut_in) * cut_in.powf(alpha + T::ONE));
+
+        let tail = Self {
+            pdf,
+            cut_in,
+            a,
+            minus_inv_alpha: -T::ONE / alpha,
+            minus_alpha_minus_one: -alpha - T::ONE,
+            scale,
+        };
+        let area = scale * a * cut_in.powf(-alpha) / alpha;
+
+        (tail, area)
+    }
+}
+
+impl<T: StableFloat> TryDistribution<T> for Tail<T> {
+    #[inline]
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let z = self.cut_in * (T::ONE - T::gen(rng)).powf(self.minus_inv_alpha);
+
+        if T::gen(rng) * self.a * z.powf(self.minus_alpha_minus_one) <= self.pdf.eval(z) {
+            Some(self.scale * z)
+        } else {
+            None
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod distributions;
-pub mod primitives;
+pub mod mcmc;
 pub mod num;
+pub mod primitives;
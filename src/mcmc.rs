@@ -0,0 +1,56 @@
+//! Markov chain Monte Carlo utilities using ETF proposal distributions.
+
+use crate::distributions::{CentralNormal, NormalError, NormalFloat};
+use crate::num::Float;
+use crate::primitives::{Distribution, UnivariateFn};
+use rand_core::RngCore;
+
+/// Performs a Metropolis-Hastings step and returns the next state of the
+/// chain.
+///
+/// The candidate is the current state shifted by a sample of the proposal
+/// distribution, which must be symmetric about 0. The candidate is accepted
+/// with probability `min(1, exp(ln π(candidate) - ln π(current)))`, where
+/// `ln π` is the target log-density up to an additive constant.
+pub fn metropolis_hastings_step<T, D, P, R>(
+    current: T,
+    target_log_pdf: &P,
+    proposal: &D,
+    rng: &mut R,
+) -> T
+where
+    T: Float,
+    D: Distribution<T>,
+    P: UnivariateFn<T>,
+    R: RngCore + ?Sized,
+{
+    let candidate = current + proposal.sample(rng);
+    let log_ratio = target_log_pdf.eval(candidate) - target_log_pdf.eval(current);
+
+    // A NaN log-ratio always rejects the candidate.
+    if T::gen(rng) < log_ratio.exp() {
+        candidate
+    } else {
+        current
+    }
+}
+
+/// Returns a random walk Metropolis-Hastings kernel with a normal proposal
+/// of standard deviation `step_size`.
+///
+/// The kernel maps the current state of the chain to the next state.
+pub fn random_walk_mh_kernel<T, P, R>(
+    target_log_pdf: P,
+    step_size: T,
+) -> Result<impl Fn(T, &mut R) -> T, NormalError>
+where
+    T: NormalFloat,
+    P: UnivariateFn<T>,
+    R: RngCore + ?Sized,
+{
+    let proposal = CentralNormal::new(step_size)?;
+
+    Ok(move |current: T, rng: &mut R| {
+        metropolis_hastings_step(current, &target_log_pdf, &proposal, rng)
+    })
+}
@@ -6,13 +6,18 @@ use rand_core::RngCore;
 use std::marker::PhantomData;
 use storage::Datum;
 
-#[cfg(feature = "rand_distribution")]
+#[cfg(any(feature = "rand_distribution", feature = "rand_interop"))]
 use rand_distr;
 
+mod half;
 pub mod partition;
+mod progressive;
 mod storage;
 pub mod util;
 
+pub use half::{DistHalfAny, DistHalfAnyTailed, HalfAnyFloat, HalfDistError};
+pub use progressive::ProgressiveDistAny;
+
 /// Univariate function.
 ///
 /// This trait is automatically implemented for `Fn(T) -> T` types. It is mostly
@@ -64,6 +69,172 @@ pub trait TryDistribution<T> {
     fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T>;
 }
 
+/// Object-safe counterpart of [`Distribution`].
+///
+/// This trait is automatically implemented for all distributions. Since
+/// `Box<dyn DynDistribution<T>>` and `&dyn DynDistribution<T>` implement
+/// [`Distribution`], it makes it possible to select distributions at runtime.
+///
+/// [`Distribution`]: trait.Distribution.html
+pub trait DynDistribution<T> {
+    /// Draws a sample using a type-erased random number generator.
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T;
+}
+
+impl<T, D: Distribution<T>> DynDistribution<T> for D {
+    #[inline]
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T {
+        self.sample(rng)
+    }
+}
+
+impl<T> Distribution<T> for Box<dyn DynDistribution<T>> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, mut rng: &mut R) -> T {
+        (**self).sample_dyn(&mut rng)
+    }
+}
+
+impl<T> Distribution<T> for &dyn DynDistribution<T> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, mut rng: &mut R) -> T {
+        (**self).sample_dyn(&mut rng)
+    }
+}
+
+/// Fills `out` with samples drawn in parallel from a distribution.
+///
+/// The output slice is split into as many chunks of (nearly) equal length as
+/// there are seeds, and each chunk is filled on a separate `rayon` task using
+/// its own random number generator seeded with `R::seed_from_u64`. Since no
+/// generator is shared, no locking is needed, and the output only depends on
+/// the seeds irrespective of the scheduling of the tasks.
+///
+/// # Panics
+///
+/// Panics if `seeds` is empty while `out` is not.
+#[cfg(feature = "rayon")]
+pub fn sample_batch_into_par<T, D, R>(dist: &D, seeds: &[u64], out: &mut [T])
+where
+    T: Send,
+    D: Distribution<T> + Sync,
+    R: RngCore + rand_core::SeedableRng,
+{
+    use rayon::prelude::*;
+
+    if out.is_empty() {
+        return;
+    }
+    assert!(!seeds.is_empty(), "at least one seed should be provided");
+
+    let chunk_len = out.len().div_ceil(seeds.len());
+    out.par_chunks_mut(chunk_len)
+        .zip(seeds.par_iter())
+        .for_each(|(chunk, &seed)| {
+            let mut rng = R::seed_from_u64(seed);
+            for x in chunk.iter_mut() {
+                *x = dist.sample(&mut rng);
+            }
+        });
+}
+
+/// Object-safe counterpart of [`TryDistribution`].
+///
+/// This trait is automatically implemented for all rejection-sampled
+/// distributions. Since `Box<dyn DynTryDistribution<T>>` and
+/// `&dyn DynTryDistribution<T>` implement [`TryDistribution`], it makes it
+/// possible to select tail envelopes at runtime.
+///
+/// [`TryDistribution`]: trait.TryDistribution.html
+pub trait DynTryDistribution<T> {
+    /// Draws a sample using a type-erased random number generator and returns
+    /// it if it passes the acceptance-rejection check.
+    fn try_sample_dyn(&self, rng: &mut dyn RngCore) -> Option<T>;
+}
+
+impl<T, D: TryDistribution<T>> DynTryDistribution<T> for D {
+    #[inline]
+    fn try_sample_dyn(&self, rng: &mut dyn RngCore) -> Option<T> {
+        self.try_sample(rng)
+    }
+}
+
+impl<T> TryDistribution<T> for Box<dyn DynTryDistribution<T>> {
+    #[inline]
+    fn try_sample<R: RngCore + ?Sized>(&self, mut rng: &mut R) -> Option<T> {
+        (**self).try_sample_dyn(&mut rng)
+    }
+}
+
+impl<T> TryDistribution<T> for &dyn DynTryDistribution<T> {
+    #[inline]
+    fn try_sample<R: RngCore + ?Sized>(&self, mut rng: &mut R) -> Option<T> {
+        (**self).try_sample_dyn(&mut rng)
+    }
+}
+
+/// Adapter implementing [`Distribution`] for a `rand_distr` distribution.
+///
+/// This makes it possible to use `rand_distr` distributions where a
+/// [`Distribution`] is expected when the `rand_distribution` feature is not
+/// enabled (if it is, both traits are the same and no adapter is needed).
+///
+/// [`Distribution`]: trait.Distribution.html
+#[cfg(feature = "rand_interop")]
+#[derive(Copy, Clone, Debug)]
+pub struct RandDistWrapper<T, D>(D, PhantomData<T>);
+
+#[cfg(feature = "rand_interop")]
+impl<T, D: rand_distr::Distribution<T>> RandDistWrapper<T, D> {
+    /// Wraps a `rand_distr` distribution.
+    pub fn new(dist: D) -> Self {
+        Self(dist, PhantomData)
+    }
+}
+
+#[cfg(feature = "rand_interop")]
+impl<T, D: rand_distr::Distribution<T>> Distribution<T> for RandDistWrapper<T, D> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        rand_distr::Distribution::sample(&self.0, rng)
+    }
+}
+
+/// Wraps a `rand_distr` distribution into a [`RandDistWrapper`].
+///
+/// [`RandDistWrapper`]: struct.RandDistWrapper.html
+#[cfg(feature = "rand_interop")]
+pub fn wrap_rand<T, D: rand_distr::Distribution<T>>(dist: D) -> RandDistWrapper<T, D> {
+    RandDistWrapper::new(dist)
+}
+
+/// Adapter implementing the `rand_distr` distribution trait for a
+/// [`Distribution`].
+///
+/// This is the reverse of [`RandDistWrapper`].
+///
+/// [`Distribution`]: trait.Distribution.html
+/// [`RandDistWrapper`]: struct.RandDistWrapper.html
+#[cfg(feature = "rand_interop")]
+#[derive(Copy, Clone, Debug)]
+pub struct EtfDistWrapper<T, D>(D, PhantomData<T>);
+
+#[cfg(feature = "rand_interop")]
+impl<T, D: Distribution<T>> EtfDistWrapper<T, D> {
+    /// Wraps a distribution.
+    pub fn new(dist: D) -> Self {
+        Self(dist, PhantomData)
+    }
+}
+
+#[cfg(feature = "rand_interop")]
+impl<T, D: Distribution<T>> rand_distr::Distribution<T> for EtfDistWrapper<T, D> {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> T {
+        Distribution::sample(&self.0, rng)
+    }
+}
+
 /// Distribution with bounded support.
 #[derive(Clone)]
 pub struct DistAny<P, T, F>
@@ -88,6 +259,47 @@ where
             func,
         }
     }
+
+    /// Replaces the function and table of the distribution.
+    ///
+    /// This is equivalent to constructing a new distribution with `new`, but
+    /// the existing table storage is reused rather than reallocated, which
+    /// makes it cheaper to update frequently a distribution with drifting
+    /// parameters.
+    pub fn retabulate(&mut self, func: F, table: &InitTable<P, T>) {
+        let max_switch = (T::UInt::ONE << (T::UInt::BITS - P::BITS)) - T::UInt::ONE;
+        process_table_in_place(&mut self.data, T::ZERO, table, max_switch);
+        self.func = func;
+    }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test, i.e. without evaluating the function.
+    ///
+    /// This is computed from the table and can be used to compare partition
+    /// sizes or tabulation tolerances.
+    pub fn efficiency_estimate(&self) -> T {
+        self.data.efficiency_estimate(false)
+    }
+}
+
+impl<P, T> DistAny<P, T, util::HistogramPdf<T>>
+where
+    P: Partition<T>,
+    T: Float,
+{
+    /// Constructs a distribution from empirical histogram data.
+    ///
+    /// The probability density function is the normalized step function of
+    /// the histogram, which is tabulated exactly with
+    /// [`util::histogram_tabulation`] irrespective of the number of bins.
+    ///
+    /// [`util::histogram_tabulation`]: util/fn.histogram_tabulation.html
+    pub fn from_histogram(bin_edges: &[T], counts: &[u64]) -> Result<Self, util::HistogramError> {
+        let pdf = util::HistogramPdf::new(bin_edges, counts)?;
+        let table = util::histogram_tabulation(&pdf);
+
+        Ok(Self::new(pdf, &table))
+    }
 }
 
 impl<P, T, F> Distribution<T> for DistAny<P, T, F>
@@ -153,6 +365,13 @@ where
     F: UnivariateFn<T>,
     E: TryDistribution<T>,
 {
+    /// Creates a distribution from a function, its table, a tail envelope
+    /// distribution and the area under the tail envelope.
+    ///
+    /// The tail area must be strictly positive: a distribution without tail
+    /// should be constructed as a [`DistAny`] instead.
+    ///
+    /// [`DistAny`]: struct.DistAny.html
     pub fn new(func: F, table: &InitTable<P, T>, tail_envelope: E, tail_area: T) -> Self {
         let tail_switch = compute_tail_switch(table, tail_area, false);
 
@@ -164,6 +383,39 @@ where
             phantom_table_size: PhantomData,
         }
     }
+
+    /// Creates a distribution from a distribution with bounded support, its
+    /// table, a tail envelope distribution and the area under the tail
+    /// envelope.
+    ///
+    /// This is equivalent to `new` with the function of `dist`, but the table
+    /// storage of `dist` is reused rather than reallocated. The table must be
+    /// the one used to construct `dist`.
+    pub fn from_bounded_dist(
+        dist: DistAny<P, T, F>,
+        table: &InitTable<P, T>,
+        tail_envelope: E,
+        tail_area: T,
+    ) -> Self {
+        let tail_switch = compute_tail_switch(table, tail_area, false);
+        let mut data = dist.data;
+        process_table_in_place(&mut data, T::ZERO, table, tail_switch);
+
+        DistAnyTailed {
+            data,
+            func: dist.func,
+            tail_envelope,
+            tail_switch,
+            phantom_table_size: PhantomData,
+        }
+    }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test, i.e. without evaluating the function
+    /// or sampling the tail.
+    pub fn efficiency_estimate(&self) -> T {
+        self.data.efficiency_estimate(false)
+    }
 }
 
 impl<P, T, F, E> Distribution<T> for DistAnyTailed<P, T, F, E>
@@ -244,6 +496,12 @@ where
             phantom_table_size: PhantomData,
         }
     }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test.
+    pub fn efficiency_estimate(&self) -> T {
+        self.data.efficiency_estimate(true)
+    }
 }
 
 impl<P, T, F> Distribution<T> for DistCentral<P, T, F>
@@ -327,6 +585,12 @@ where
             phantom_table_size: PhantomData,
         }
     }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test.
+    pub fn efficiency_estimate(&self) -> T {
+        self.data.efficiency_estimate(true)
+    }
 }
 
 impl<P, T, F, E> Distribution<T> for DistCentralTailed<P, T, F, E>
@@ -415,6 +679,12 @@ where
             phantom_table_size: PhantomData,
         }
     }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test.
+    pub fn efficiency_estimate(&self) -> T {
+        self.data.efficiency_estimate(true)
+    }
 }
 
 impl<P, T, F> Distribution<T> for DistSymmetric<P, T, F>
@@ -489,6 +759,9 @@ where
     F: UnivariateFn<T>,
     E: TryDistribution<T>,
 {
+    /// Creates a distribution symmetric about `x0` from a function tabulated
+    /// to the right of `x0`, a tail envelope distribution of either tail and
+    /// the area under the tail envelope.
     pub fn new(x0: T, func: F, table: &InitTable<P, T>, tail_envelope: E, tail_area: T) -> Self {
         let tail_switch = compute_tail_switch(table, tail_area, true);
 
@@ -501,6 +774,12 @@ where
             phantom_table_size: PhantomData,
         }
     }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test.
+    pub fn efficiency_estimate(&self) -> T {
+        self.data.efficiency_estimate(true)
+    }
 }
 
 impl<P, T, F, E> Distribution<T> for DistSymmetricTailed<P, T, F, E>
@@ -540,7 +819,10 @@ where
                 }
             }
 
-            // Check if the tail should be sampled.
+            // Check if the tail should be sampled. The tail sample is
+            // reflected about `x0` with the random sign: since `s` is
+            // independent of the sample, this is correct for any `x0` and for
+            // an envelope of either the right or the left tail.
             if u > self.tail_switch {
                 if let Some(x) = self.tail_envelope.try_sample(rng) {
                     return self.x0 + T::bitxor(x - self.x0, s);
@@ -562,6 +844,74 @@ where
     }
 }
 
+/// Probability density function transformed to log-space.
+///
+/// If `f` is the probability density function of a random variable `X` over
+/// `(0, ∞)`, this is the probability density function of `Y = ln(X)`:
+///
+/// ```text
+/// f_Y(y) = f(exp(y)) exp(y)
+/// ```
+///
+/// This is meant to be tabulated and sampled with a [`LogTransformedDist`].
+///
+/// [`LogTransformedDist`]: struct.LogTransformedDist.html
+#[derive(Copy, Clone, Debug)]
+pub struct LogTransformedFn<F> {
+    func: F,
+}
+
+impl<F> LogTransformedFn<F> {
+    /// Creates the log-space transform of function `func`.
+    pub fn new(func: F) -> Self {
+        Self { func }
+    }
+}
+
+impl<T: Float, F: UnivariateFn<T>> UnivariateFn<T> for LogTransformedFn<F> {
+    #[inline]
+    fn eval(&self, y: T) -> T {
+        let x = y.exp();
+
+        self.func.eval(x) * x
+    }
+}
+
+/// Distribution over `(0, ∞)` sampled in log-space.
+///
+/// Distributions with a probability density function highly concentrated near
+/// zero or with a heavy right tail are often easier to tabulate for the
+/// variable `Y = ln(X)`. This wraps a distribution of `Y`, typically a
+/// [`DistAny`] or a [`DistAnyTailed`] for a [`LogTransformedFn`], and samples
+/// `X` as `exp(Y)`.
+///
+/// [`DistAny`]: struct.DistAny.html
+/// [`DistAnyTailed`]: struct.DistAnyTailed.html
+/// [`LogTransformedFn`]: struct.LogTransformedFn.html
+#[derive(Clone)]
+pub struct LogTransformedDist<D> {
+    inner: D,
+}
+
+impl<D> LogTransformedDist<D> {
+    /// Creates a distribution from the distribution `inner` of `ln(X)`.
+    pub fn new(inner: D) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the distribution of `ln(X)`.
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+}
+
+impl<T: Float, D: Distribution<T>> Distribution<T> for LogTransformedDist<D> {
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng).exp()
+    }
+}
+
 #[derive(Clone)]
 struct Data<P, T>
 where
@@ -572,15 +922,58 @@ where
     scaled_xysup: T, // dx * ysup / tail_switch
 }
 
+impl<P, T> Data<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+{
+    // Probability that the rectangle test succeeds. Since the ETF
+    // subintervals have equal areas, the table index is drawn uniformly and
+    // the test succeeds for significands up to the wedge switch out of the
+    // significands left by the index (and sign) bits.
+    fn efficiency_estimate(&self, is_symmetric: bool) -> T {
+        let sign_bit_width = if is_symmetric { 1 } else { 0 };
+        let u_max = T::cast_uint(
+            (T::UInt::ONE << (T::UInt::BITS - P::BITS - sign_bit_width)) - T::UInt::ONE,
+        );
+
+        let mut sum = T::ZERO;
+        for i in 0..P::SIZE {
+            sum += T::cast_uint(self.table[i].wedge_switch) + T::ONE;
+        }
+
+        sum / (T::cast_usize(P::SIZE) * (u_max + T::ONE))
+    }
+}
+
 // Generates an optimized lookup table from a quadrature table.
 fn process_table<P, T>(x0: T, init_table: &InitTable<P, T>, tail_switch: T::UInt) -> Data<P, T>
 where
     P: Partition<T>,
     T: Float,
+{
+    let mut data = Data {
+        table: DataArray::default(),
+        scaled_xysup: T::ZERO,
+    };
+    process_table_in_place(&mut data, x0, init_table, tail_switch);
+
+    data
+}
+
+// Same as `process_table`, but overwrites existing data.
+fn process_table_in_place<P, T>(
+    data: &mut Data<P, T>,
+    x0: T,
+    init_table: &InitTable<P, T>,
+    tail_switch: T::UInt,
+) where
+    P: Partition<T>,
+    T: Float,
 {
     let max_bit_loss = T::ONE;
     let n = P::SIZE;
-    let mut table = DataArray::default();
+    let table = &mut data.table;
 
     // Convenient aliases.
     let x = &init_table.x;
@@ -596,6 +989,10 @@ where
         // resolution. In order to avoid this loss of sampling quality, yinf is
         // in such case set to 0, which unconditionally forces the use of the
         // more expensive but higher quality wedge sampling algorithm.
+        //
+        // Note that if `yinf` is 0 (or if `yinf/ysup` is NaN), the bit loss is
+        // infinite (resp. NaN) so the degraded case is selected and `alpha` is
+        // never computed by division by 0.
         let w = yinf[i] / ysup[i] * T::cast_uint(tail_switch);
         let bit_loss = T::cast_u32(T::SIGNIFICAND_BITS) - w.log2();
         let (wedge_switch, alpha) = if bit_loss <= max_bit_loss {
@@ -605,6 +1002,7 @@ where
             // Degraded case: force wedge sampling algorithm.
             (T::UInt::ZERO, T::ZERO)
         };
+        debug_assert!(alpha.is_finite());
 
         table[i] = Datum {
             alpha,
@@ -620,13 +1018,10 @@ where
         wedge_switch: T::UInt::ZERO, // never used
     };
 
-    // Scaled area of a single rectangle.
-    let scaled_xysup = (x[1] - x[0]) * ysup[0] / T::cast_uint(tail_switch);
-
-    Data {
-        table,
-        scaled_xysup,
-    }
+    // Scaled area of a single rectangle, skipping zero-width rectangles
+    // whose height is not equalized.
+    let i = (0..n).find(|&i| x[i + 1] != x[i]).unwrap_or(0);
+    data.scaled_xysup = (x[i + 1] - x[i]) * ysup[i] / T::cast_uint(tail_switch);
 }
 
 // Computes the integer used as a threshold for tail sampling.
@@ -639,6 +1034,10 @@ where
     P: Partition<T>,
     T: Float,
 {
+    // A zero tail area would make the tail unreachable, which is better
+    // handled by the distributions without tail.
+    debug_assert!(tail_area > T::ZERO);
+
     let x = &init_table.x;
     let ysup = &init_table.ysup;
 
@@ -650,5 +1049,20 @@ where
     let max_switch =
         T::cast_uint((T::UInt::ONE << (T::UInt::BITS - P::BITS - sign_bit_width)) - T::UInt::ONE);
 
-    (max_switch * (area / (area + tail_area))).round_as_uint()
+    // If the tail area is many orders of magnitude larger than the table area,
+    // the switch would be rounded to 0, making `scaled_xysup` infinite so the
+    // table could never be sampled. A switch of at least 1 is thus enforced,
+    // which also catches a NaN or infinite tail area.
+    let switch = max_switch * (area / (area + tail_area));
+    #[cfg(feature = "log")]
+    if switch < T::ONE || switch.is_nan() {
+        log::warn!(
+            "tail switch {} clamped to 1 (table area: {}, tail area: {})",
+            switch,
+            area,
+            tail_area
+        );
+    }
+
+    switch.max(T::ONE).round_as_uint()
 }
@@ -0,0 +1,186 @@
+//! Distributions with one-sided support.
+
+use super::partition::{InitTable, Partition};
+use super::{DistAny, DistAnyTailed, Distribution, TryDistribution, UnivariateFn};
+use crate::num::Float;
+
+use rand_core::RngCore;
+use thiserror::Error;
+
+/// A floating point type for use with one-sided distributions.
+pub trait HalfAnyFloat: Float {}
+
+impl HalfAnyFloat for f32 {}
+
+impl HalfAnyFloat for f64 {}
+
+/// Error type for one-sided distribution construction failures.
+#[derive(Error, Debug)]
+pub enum HalfDistError {
+    /// The provided left boundary is negative or not finite.
+    #[error("the left boundary should be finite and non-negative")]
+    BadLeftBoundary,
+    /// The table extends to the left of the left boundary.
+    #[error("the table should not extend to the left of the left boundary")]
+    TableOutsideSupport,
+    /// The function is negative at a node of the table.
+    #[error("the probability density function should be non-negative")]
+    NegativePdf,
+}
+
+/// Distribution with one-sided bounded support.
+///
+/// This is a [`DistAny`] which only samples from `[x_left, x_right]`, where
+/// `x_left` is a specified left boundary and `x_right` the last node of the
+/// table. The probability density function need not be defined to the left of
+/// `x_left`.
+///
+/// Unlike with a [`DistAny`], the construction checks that the table does not
+/// extend to the left of `x_left` and that the function is non-negative at the
+/// nodes of the table, which catches the common mistake of tabulating a
+/// two-sided function when only one side is intended.
+///
+/// [`DistAny`]: struct.DistAny.html
+#[derive(Clone)]
+pub struct DistHalfAny<P, T, F>
+where
+    P: Partition<T>,
+    T: HalfAnyFloat,
+{
+    inner: DistAny<P, T, F>,
+    x_left: T,
+}
+
+impl<P, T, F> DistHalfAny<P, T, F>
+where
+    P: Partition<T>,
+    T: HalfAnyFloat,
+    F: UnivariateFn<T>,
+{
+    /// Creates a distribution from its left boundary, a function and its
+    /// table.
+    pub fn new(x_left: T, func: F, table: &InitTable<P, T>) -> Result<Self, HalfDistError> {
+        validate(x_left, &func, table)?;
+
+        Ok(Self {
+            inner: DistAny::new(func, table),
+            x_left,
+        })
+    }
+
+    /// Returns the left boundary of the support.
+    pub fn x_left(&self) -> T {
+        self.x_left
+    }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test.
+    pub fn efficiency_estimate(&self) -> T {
+        self.inner.efficiency_estimate()
+    }
+}
+
+impl<P, T, F> Distribution<T> for DistHalfAny<P, T, F>
+where
+    P: Partition<T>,
+    T: HalfAnyFloat,
+    F: UnivariateFn<T>,
+{
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
+
+/// Distribution with one-sided support and rejection-sampled right tail.
+///
+/// This is a [`DistAnyTailed`] which only samples from `[x_left, ∞)`, where
+/// `x_left` is a specified left boundary. The table must start at or after
+/// `x_left` and the tail envelope must only generate samples to the right of
+/// the last node of the table.
+///
+/// The construction performs the same checks as for a [`DistHalfAny`].
+///
+/// [`DistAnyTailed`]: struct.DistAnyTailed.html
+/// [`DistHalfAny`]: struct.DistHalfAny.html
+#[derive(Clone)]
+pub struct DistHalfAnyTailed<P, T, F, E>
+where
+    P: Partition<T>,
+    T: HalfAnyFloat,
+{
+    inner: DistAnyTailed<P, T, F, E>,
+    x_left: T,
+}
+
+impl<P, T, F, E> DistHalfAnyTailed<P, T, F, E>
+where
+    P: Partition<T>,
+    T: HalfAnyFloat,
+    F: UnivariateFn<T>,
+    E: TryDistribution<T>,
+{
+    /// Creates a distribution from its left boundary, a function, its table,
+    /// a right tail envelope distribution and the area under the tail
+    /// envelope.
+    pub fn new(
+        x_left: T,
+        func: F,
+        table: &InitTable<P, T>,
+        tail_envelope: E,
+        tail_area: T,
+    ) -> Result<Self, HalfDistError> {
+        validate(x_left, &func, table)?;
+
+        Ok(Self {
+            inner: DistAnyTailed::new(func, table, tail_envelope, tail_area),
+            x_left,
+        })
+    }
+
+    /// Returns the left boundary of the support.
+    pub fn x_left(&self) -> T {
+        self.x_left
+    }
+
+    /// Returns the probability that an iteration of the sampling loop
+    /// succeeds with the rectangle test.
+    pub fn efficiency_estimate(&self) -> T {
+        self.inner.efficiency_estimate()
+    }
+}
+
+impl<P, T, F, E> Distribution<T> for DistHalfAnyTailed<P, T, F, E>
+where
+    P: Partition<T>,
+    T: HalfAnyFloat,
+    F: UnivariateFn<T>,
+    E: TryDistribution<T>,
+{
+    #[inline]
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        self.inner.sample(rng)
+    }
+}
+
+// Checks the left boundary, the position of the table and the sign of the
+// function at the nodes.
+fn validate<P, T, F>(x_left: T, func: &F, table: &InitTable<P, T>) -> Result<(), HalfDistError>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    if x_left < T::ZERO || !x_left.is_finite() {
+        return Err(HalfDistError::BadLeftBoundary);
+    }
+    // The table may be given from right to left.
+    if table.x[0].min(table.x[P::SIZE]) < x_left {
+        return Err(HalfDistError::TableOutsideSupport);
+    }
+    if (0..=P::SIZE).any(|i| func.eval(table.x[i]) < T::ZERO) {
+        return Err(HalfDistError::NegativePdf);
+    }
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 //! Interval partitions and related data structures.
 
 use super::storage::{Datum, Storage};
-use crate::num::Float;
+use super::UnivariateFn;
+use crate::num::{Float, UInt};
+use std::fmt;
 use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
@@ -15,6 +17,15 @@ pub trait Partition<T: Float>: Clone {
     type NodeStorage: Storage<T>;
     #[doc(hidden)]
     type DataStorage: Storage<Datum<T>>;
+
+    /// Returns `true` if this partition has more subintervals than partition
+    /// `Q`.
+    ///
+    /// This is equivalent to `Self::BITS > Q::BITS`, which may be used
+    /// instead where a constant expression is required.
+    fn is_larger_than<Q: Partition<T>>() -> bool {
+        Self::BITS > Q::BITS
+    }
 }
 
 macro_rules! make_partition {
@@ -24,7 +35,20 @@ macro_rules! make_partition {
         #[doc = "subintervals."]
         #[derive(Clone)]
         pub struct $p<T> {
-            _phantom: PhantomData<T>
+            _phantom: PhantomData<T>,
+        }
+        impl<T> $p<T> {
+            /// Creates the partition marker.
+            pub const fn new() -> Self {
+                Self {
+                    _phantom: PhantomData,
+                }
+            }
+        }
+        impl<T> Default for $p<T> {
+            fn default() -> Self {
+                Self::new()
+            }
         }
         impl<T: Float> Partition<T> for $p<T> {
             const BITS: u32 = $bits;
@@ -37,7 +61,7 @@ macro_rules! make_partition {
 
     ($p:ident, $sz:expr, $bits:expr) => {
         make_partition!($p, $sz, stringify!($sz), $bits);
-    }
+    };
 }
 make_partition!(P16, 16, 4);
 make_partition!(P32, 32, 5);
@@ -129,3 +153,319 @@ impl<P: Partition<T>, T: Float> Default for InitTable<P, T> {
         }
     }
 }
+
+impl<P: Partition<T>, T: Float> InitTable<P, T> {
+    /// Returns the position of the `i`-th node, where `i` lies within
+    /// `[0, P::SIZE]`.
+    pub fn node(&self, i: usize) -> T {
+        self.x[i]
+    }
+
+    /// Returns the lower bound of the function over the `i`-th subinterval,
+    /// where `i` lies within `[0, P::SIZE)`.
+    pub fn lower_bound(&self, i: usize) -> T {
+        self.yinf[i]
+    }
+
+    /// Returns the upper bound of the function over the `i`-th subinterval,
+    /// where `i` lies within `[0, P::SIZE)`.
+    pub fn upper_bound(&self, i: usize) -> T {
+        self.ysup[i]
+    }
+
+    /// Returns an iterator over the subintervals, yielding the left node
+    /// position and the lower and upper bounds of the function over each
+    /// subinterval.
+    pub fn iter(&self) -> impl Iterator<Item = (T, T, T)> + '_ {
+        (0..P::SIZE).map(move |i| (self.x[i], self.yinf[i], self.ysup[i]))
+    }
+
+    /// Returns the maximum absolute differences between the node positions
+    /// and function bounds of this table and another table.
+    pub fn diff(&self, other: &Self) -> TableDiff<T> {
+        // Returns the maximum absolute difference and its index, or the first
+        // non-finite difference and its index.
+        fn max_diff<T: Float>(
+            n: usize,
+            a: impl Fn(usize) -> T,
+            b: impl Fn(usize) -> T,
+        ) -> (T, usize) {
+            let mut max = (T::ZERO, 0);
+            for i in 0..n {
+                let d = (a(i) - b(i)).abs();
+                if !d.is_finite() {
+                    return (d, i);
+                }
+                if d > max.0 {
+                    max = (d, i);
+                }
+            }
+
+            max
+        }
+
+        let (max_x_diff, max_x_index) = max_diff(P::SIZE + 1, |i| self.x[i], |i| other.x[i]);
+        let (max_yinf_diff, max_yinf_index) =
+            max_diff(P::SIZE, |i| self.yinf[i], |i| other.yinf[i]);
+        let (max_ysup_diff, max_ysup_index) =
+            max_diff(P::SIZE, |i| self.ysup[i], |i| other.ysup[i]);
+
+        TableDiff {
+            max_x_diff,
+            max_x_index,
+            max_yinf_diff,
+            max_yinf_index,
+            max_ysup_diff,
+            max_ysup_index,
+        }
+    }
+
+    /// Returns an ASCII plot of the upper bounds of the function relative to
+    /// their maximum, with `height` lines of `width` characters.
+    ///
+    /// Each column shows the largest upper bound over a group of consecutive
+    /// subintervals, so the plot is not to scale along the `x` axis.
+    #[cfg(feature = "debug_utils")]
+    pub fn plot_ascii(&self, width: usize, height: usize) -> String {
+        if width == 0 || height == 0 {
+            return String::new();
+        }
+
+        let y_max = (0..P::SIZE).fold(T::ZERO, |y_max, i| y_max.max(self.ysup[i]));
+        let column_heights: Vec<usize> = (0..width)
+            .map(|j| {
+                let start = j * P::SIZE / width;
+                let end = ((j + 1) * P::SIZE / width).max(start + 1).min(P::SIZE);
+                let y = (start..end).fold(T::ZERO, |y, i| y.max(self.ysup[i]));
+                if y_max > T::ZERO {
+                    (y / y_max * T::cast_usize(height))
+                        .round_as_uint()
+                        .as_usize()
+                } else {
+                    0
+                }
+            })
+            .collect();
+
+        let mut plot = String::with_capacity((width + 1) * height);
+        for level in (1..=height).rev() {
+            for &h in &column_heights {
+                plot.push(if h >= level { '#' } else { ' ' });
+            }
+            plot.push('\n');
+        }
+
+        plot
+    }
+
+    /// Returns `true` if the node positions and function bounds of this table
+    /// and another table differ by at most `tolerance`.
+    ///
+    /// Tables with a non-finite difference, for instance due to a NaN value,
+    /// are never deemed close.
+    pub fn is_close(&self, other: &Self, tolerance: T) -> bool {
+        let diff = self.diff(other);
+        let is_within = |d: T| d.is_finite() && d <= tolerance;
+
+        is_within(diff.max_x_diff) && is_within(diff.max_yinf_diff) && is_within(diff.max_ysup_diff)
+    }
+}
+
+/// Prints a text table with the nodes and the bounds of the function over
+/// each subinterval.
+///
+/// The values are printed with 4 decimal places unless a precision is
+/// specified with the format string.
+impl<P: Partition<T>, T: Float> fmt::Display for InitTable<P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+        writeln!(
+            f,
+            "{:<6} {:>14} {:>14} {:>14} {:>14}",
+            "i", "x[i]", "x[i+1]", "yinf[i]", "ysup[i]"
+        )?;
+        for i in 0..P::SIZE {
+            writeln!(
+                f,
+                "{:<6} {:>14.*} {:>14.*} {:>14.*} {:>14.*}",
+                i,
+                precision,
+                self.x[i],
+                precision,
+                self.x[i + 1],
+                precision,
+                self.yinf[i],
+                precision,
+                self.ysup[i]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Maximum absolute differences between two initialization tables.
+///
+/// If several nodes or subintervals share the maximum difference, the index
+/// is that of the first one. A non-finite difference, for instance due to a
+/// NaN value, takes precedence over all finite differences.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TableDiff<T> {
+    /// Maximum difference between node positions.
+    pub max_x_diff: T,
+    /// Index of the node with the maximum position difference.
+    pub max_x_index: usize,
+    /// Maximum difference between function lower bounds.
+    pub max_yinf_diff: T,
+    /// Index of the subinterval with the maximum lower bound difference.
+    pub max_yinf_index: usize,
+    /// Maximum difference between function upper bounds.
+    pub max_ysup_diff: T,
+    /// Index of the subinterval with the maximum upper bound difference.
+    pub max_ysup_index: usize,
+}
+
+impl<T: fmt::Display> fmt::Display for TableDiff<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "max x diff: {} (node {}), max yinf diff: {} (subinterval {}), max ysup diff: {} (subinterval {})",
+            self.max_x_diff,
+            self.max_x_index,
+            self.max_yinf_diff,
+            self.max_yinf_index,
+            self.max_ysup_diff,
+            self.max_ysup_index
+        )
+    }
+}
+
+/// Table of the quantiles of a distribution at evenly spaced probabilities.
+///
+/// The quantiles are those of the distribution restricted to the interval
+/// spanned by the nodes of an initialization table, so the first and last
+/// quantiles are the first and last nodes of the table.
+#[derive(Clone)]
+pub struct QuantileTable<P: Partition<T>, T: Float> {
+    q: NodeArray<P, T>,
+    cdf_min: T,
+    cdf_max: T,
+}
+
+impl<P: Partition<T>, T: Float> QuantileTable<P, T> {
+    /// Computes the quantile table from an initialization table and the
+    /// cumulative distribution function.
+    ///
+    /// The CDF needs not be normalized; the `i`-th quantile is the position
+    /// where the CDF is `F(x₀) + (i/N)·(F(xₙ) - F(x₀))`, where `x₀` and `xₙ`
+    /// are the first and last nodes and `N` is the partition size.
+    pub fn from_init_table<C: UnivariateFn<T>>(table: &InitTable<P, T>, cdf: &C) -> Self {
+        let mut q = NodeArray::<P, T>::default();
+        let cdf_min = cdf.eval(table.x[0]);
+        let cdf_max = cdf.eval(table.x[P::SIZE]);
+        q[0] = table.x[0];
+        q[P::SIZE] = table.x[P::SIZE];
+
+        // Subinterval containing the current quantile and CDF at its right node.
+        let mut j = 0;
+        let mut cdf_right = cdf.eval(table.x[1]);
+        for i in 1..P::SIZE {
+            let target = cdf_min + (cdf_max - cdf_min) * T::cast_usize(i) / T::cast_usize(P::SIZE);
+            while j + 1 < P::SIZE && cdf_right < target {
+                j += 1;
+                cdf_right = cdf.eval(table.x[j + 1]);
+            }
+
+            // Bisect until the bracket cannot be narrowed any further.
+            let mut a = table.x[j].max(q[i - 1]);
+            let mut b = table.x[j + 1];
+            loop {
+                let m = (a + b) * T::ONE_HALF;
+                if m <= a || m >= b || b - a <= T::EPSILON * a.abs().max(b.abs()) {
+                    break;
+                }
+                if cdf.eval(m) < target {
+                    a = m;
+                } else {
+                    b = m;
+                }
+            }
+            q[i] = b;
+        }
+
+        Self {
+            q,
+            cdf_min,
+            cdf_max,
+        }
+    }
+
+    /// Returns the `i`-th quantile, where `i` lies within `[0, P::SIZE]`.
+    pub fn quantile(&self, i: usize) -> T {
+        self.q[i]
+    }
+
+    /// Returns an approximation of the quantile at probability `p` by linear
+    /// interpolation between tabulated quantiles.
+    ///
+    /// The probability is clamped to `[0, 1]`.
+    pub fn quantile_approx(&self, p: T) -> T {
+        let (i, s) = self.locate(p);
+
+        self.q[i] + (self.q[i + 1] - self.q[i]) * s
+    }
+
+    /// Returns the quantile at probability `p` using the linear approximation
+    /// as initial guess for a safeguarded Newton solve.
+    ///
+    /// The CDF and PDF must be those used to compute the table, with the same
+    /// normalization. The probability is clamped to `[0, 1]`.
+    pub fn quantile_refined<C, F>(&self, p: T, cdf: &C, pdf: &F) -> T
+    where
+        C: UnivariateFn<T>,
+        F: UnivariateFn<T>,
+    {
+        const MAX_ITER: u32 = 50;
+
+        let (i, s) = self.locate(p);
+        let p = p.max(T::ZERO).min(T::ONE);
+        let target = self.cdf_min + (self.cdf_max - self.cdf_min) * p;
+        let mut a = self.q[i];
+        let mut b = self.q[i + 1];
+        let mut x = a + (b - a) * s;
+        for _ in 0..MAX_ITER {
+            let y = cdf.eval(x) - target;
+            if y == T::ZERO {
+                break;
+            }
+            if y < T::ZERO {
+                a = x;
+            } else {
+                b = x;
+            }
+
+            // Fall back to bisection if the Newton step leaves the bracket.
+            let mut x_new = x - y / pdf.eval(x);
+            if !(x_new > a && x_new < b) {
+                x_new = (a + b) * T::ONE_HALF;
+            }
+            let converged = (x_new - x).abs() <= T::EPSILON * x.abs();
+            x = x_new;
+            if converged {
+                break;
+            }
+        }
+
+        x
+    }
+
+    // Returns the index of the tabulated quantile immediately below
+    // probability `p` and the normalized position of `p` between this
+    // quantile and the next one.
+    fn locate(&self, p: T) -> (usize, T) {
+        let s = p.max(T::ZERO).min(T::ONE) * T::cast_usize(P::SIZE);
+        let i = s.as_uint().as_usize().min(P::SIZE - 1);
+
+        (i, s - T::cast_usize(i))
+    }
+}
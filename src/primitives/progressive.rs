@@ -0,0 +1,313 @@
+//! Distribution with bounded support refined from sampling statistics.
+
+use super::partition::{InitTable, Partition};
+use super::{Distribution, UnivariateFn};
+use crate::num::Float;
+
+use rand_core::RngCore;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+
+/// Distribution with bounded support and a table that is progressively refined
+/// in the regions where rejections are most frequent.
+///
+/// The distribution is initialized from an ETF table but, unlike [`DistAny`],
+/// its rectangles do not need to have equal areas: a rectangle is selected
+/// with a probability proportional to its area using Walker's alias method,
+/// and a point is then drawn uniformly within the rectangle and accepted
+/// if it lies below the function.
+///
+/// The number of samples drawn from each rectangle and the number of
+/// rejections are tracked. Every `refine_period` samples, the 5% of rectangles
+/// with the highest rejection rate are bisected and the alias table is
+/// rebuilt, which progressively improves the acceptance rate for functions
+/// that are poorly approximated by the initial table. Refinement stops once
+/// the table reaches its maximum number of rectangles, and a rectangle is not
+/// bisected if it is too narrow to be split at floating point resolution.
+///
+/// The supremum and infimum of the function over the new sub-intervals are
+/// determined from the function values at their boundaries and at the
+/// extrema of the function, which must therefore be specified. The
+/// distribution is thus exact at all times: refinement only affects
+/// efficiency.
+///
+/// This implementation uses interior mutability without synchronization and
+/// is therefore not `Sync`.
+///
+/// [`DistAny`]: struct.DistAny.html
+pub struct ProgressiveDistAny<P, T, F>
+where
+    P: Partition<T>,
+    T: Float,
+{
+    func: F,
+    extrema: Vec<(T, T)>,
+    table: RefCell<Table<T>>,
+    countdown: Cell<u64>, // number of samples until the next refinement
+    refine_period: u64,
+    max_len: usize,
+    phantom_table_size: PhantomData<P>,
+}
+
+impl<P, T, F> ProgressiveDistAny<P, T, F>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    /// Creates a distribution from an ETF table, the ordered sequence
+    /// `x_extrema` of the extrema of function `func` and the number of samples
+    /// between two refinements.
+    ///
+    /// The table may grow up to 64 times its initial size.
+    pub fn new(func: F, table: &InitTable<P, T>, x_extrema: &[T], refine_period: u64) -> Self {
+        Self::with_max_len(func, table, x_extrema, refine_period, 64 * P::SIZE)
+    }
+
+    /// Creates a distribution from an ETF table, the ordered sequence
+    /// `x_extrema` of the extrema of function `func`, the number of samples
+    /// between two refinements and the maximum number of rectangles.
+    pub fn with_max_len(
+        func: F,
+        table: &InitTable<P, T>,
+        x_extrema: &[T],
+        refine_period: u64,
+        max_len: usize,
+    ) -> Self {
+        // The nodes may be in increasing or decreasing order.
+        let (x_min, x_max) = min_max(table.x[0], table.x[P::SIZE]);
+        let extrema = x_extrema
+            .iter()
+            .filter(|&&x_e| x_e > x_min && x_e < x_max)
+            .map(|&x_e| (x_e, func.eval(x_e)))
+            .collect();
+        let rectangles = (0..P::SIZE)
+            .map(|i| Rectangle::new(table.x[i], table.x[i + 1], table.yinf[i], table.ysup[i]))
+            .collect();
+
+        Self {
+            func,
+            extrema,
+            table: RefCell::new(Table::new(rectangles)),
+            countdown: Cell::new(refine_period.max(1)),
+            refine_period: refine_period.max(1),
+            max_len,
+            phantom_table_size: PhantomData,
+        }
+    }
+
+    /// Returns the current number of rectangles.
+    pub fn len(&self) -> usize {
+        self.table.borrow().rectangles.len()
+    }
+
+    /// Returns `true` if the table contains no rectangles.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the ratio of the area under the function to the total area of
+    /// the rectangles, which is the expected acceptance rate.
+    pub fn efficiency(&self) -> T {
+        let table = self.table.borrow();
+        let one_half = T::ONE / (T::ONE + T::ONE);
+        // The area under the function is estimated with the trapezoidal rule
+        // over the boundaries of the rectangles, which is accurate enough to
+        // monitor the refinement.
+        let area_under = table.rectangles.iter().fold(T::ZERO, |s, r| {
+            s + one_half * (r.x1 - r.x0) * (self.func.eval(r.x0) + self.func.eval(r.x1))
+        });
+
+        area_under / table.total_area
+    }
+
+    // Bisects the rectangles with the highest rejection rates.
+    fn refine(&self) {
+        let mut table = self.table.borrow_mut();
+        let n = table.rectangles.len();
+        if n >= self.max_len {
+            return;
+        }
+        let split_count = (n / 20).max(1).min(self.max_len - n);
+
+        // Move the indices with the highest rejection rates to the front.
+        let rate = |r: &Rectangle<T>| {
+            if r.hits.get() == 0 {
+                0.0
+            } else {
+                r.rejections.get() as f64 / r.hits.get() as f64
+            }
+        };
+        let mut indices: Vec<usize> = (0..n).collect();
+        if split_count < n {
+            indices.select_nth_unstable_by(split_count, |&a, &b| {
+                rate(&table.rectangles[b])
+                    .partial_cmp(&rate(&table.rectangles[a]))
+                    .unwrap()
+            });
+        }
+
+        // Rectangles without rejections are not split, and neither are those
+        // which are too narrow for their midpoint to differ from both bounds.
+        let one_half = T::ONE / (T::ONE + T::ONE);
+        let mut split = vec![false; n];
+        let mut is_refined = false;
+        for &i in indices.iter().take(split_count) {
+            let r = &table.rectangles[i];
+            let x_mid = one_half * (r.x0 + r.x1);
+            split[i] = r.rejections.get() > 0 && x_mid != r.x0 && x_mid != r.x1;
+            is_refined |= split[i];
+        }
+        if !is_refined {
+            return;
+        }
+
+        let mut rectangles = Vec::with_capacity(n + split_count);
+        for (r, &split) in table.rectangles.iter().zip(split.iter()) {
+            if split {
+                let x_mid = one_half * (r.x0 + r.x1);
+                rectangles.push(self.sub_rectangle(r.x0, x_mid));
+                rectangles.push(self.sub_rectangle(x_mid, r.x1));
+            } else {
+                rectangles.push(Rectangle::new(r.x0, r.x1, r.yinf, r.ysup));
+            }
+        }
+
+        *table = Table::new(rectangles);
+    }
+
+    // Computes the rectangle bounding the function over [x0, x1].
+    fn sub_rectangle(&self, x0: T, x1: T) -> Rectangle<T> {
+        let y0 = self.func.eval(x0);
+        let y1 = self.func.eval(x1);
+        let (mut yinf, mut ysup) = min_max(y0, y1);
+        let (x_min, x_max) = min_max(x0, x1);
+        for &(x_e, y_e) in self.extrema.iter() {
+            if x_e > x_min && x_e < x_max {
+                yinf = yinf.min(y_e);
+                ysup = ysup.max(y_e);
+            }
+        }
+
+        Rectangle::new(x0, x1, yinf, ysup)
+    }
+}
+
+impl<P, T, F> Distribution<T> for ProgressiveDistAny<P, T, F>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        let countdown = self.countdown.get() - 1;
+        if countdown == 0 {
+            self.refine();
+            self.countdown.set(self.refine_period);
+        } else {
+            self.countdown.set(countdown);
+        }
+
+        let table = self.table.borrow();
+        loop {
+            // Select a rectangle with the alias method.
+            let n = table.rectangles.len();
+            let i = ((u128::from(rng.next_u64()) * n as u128) >> 64) as usize;
+            let i = if T::gen(rng) < table.prob[i] {
+                i
+            } else {
+                table.alias[i]
+            };
+            let r = &table.rectangles[i];
+            r.hits.set(r.hits.get() + 1);
+
+            // Draw a point within the rectangle.
+            let x = r.x0 + T::gen(rng) * (r.x1 - r.x0);
+            let y = T::gen(rng) * r.ysup;
+            if y <= r.yinf || self.func.test(x, T::ONE, y) {
+                return x;
+            }
+            r.rejections.set(r.rejections.get() + 1);
+        }
+    }
+}
+
+// Returns the pair `(min(a, b), max(a, b))`.
+fn min_max<T: Float>(a: T, b: T) -> (T, T) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+// Rectangle with sampling statistics.
+struct Rectangle<T> {
+    x0: T,
+    x1: T,
+    yinf: T,
+    ysup: T,
+    hits: Cell<u64>,
+    rejections: Cell<u64>,
+}
+
+impl<T: Float> Rectangle<T> {
+    fn new(x0: T, x1: T, yinf: T, ysup: T) -> Self {
+        Self {
+            x0,
+            x1,
+            yinf,
+            ysup,
+            hits: Cell::new(0),
+            rejections: Cell::new(0),
+        }
+    }
+
+    fn area(&self) -> T {
+        (self.x1 - self.x0).abs() * self.ysup
+    }
+}
+
+// Rectangles and the associated alias table.
+struct Table<T> {
+    rectangles: Vec<Rectangle<T>>,
+    prob: Vec<T>,
+    alias: Vec<usize>,
+    total_area: T,
+}
+
+impl<T: Float> Table<T> {
+    // Builds the alias table with Vose's algorithm.
+    fn new(rectangles: Vec<Rectangle<T>>) -> Self {
+        let n = rectangles.len();
+        let total_area = rectangles.iter().fold(T::ZERO, |s, r| s + r.area());
+        let mut prob: Vec<T> = rectangles
+            .iter()
+            .map(|r| r.area() * T::cast_usize(n) / total_area)
+            .collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&i| prob[i] < T::ONE);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            alias[s] = l;
+            prob[l] = (prob[l] + prob[s]) - T::ONE;
+            if prob[l] < T::ONE {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // Left-over entries only differ from 1 by round-off errors.
+        for i in small.into_iter().chain(large) {
+            prob[i] = T::ONE;
+        }
+
+        Self {
+            rectangles,
+            prob,
+            alias,
+            total_area,
+        }
+    }
+}
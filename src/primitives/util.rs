@@ -3,6 +3,8 @@
 use super::{InitTable, NodeArray, Partition, TryDistribution, UnivariateFn};
 use crate::num::Float;
 use rand_core::RngCore;
+use std::cmp::Ordering;
+use std::ops::Add;
 use thiserror::Error;
 
 /// An error that can occur during a tabulation computation.
@@ -12,6 +14,53 @@ pub enum TabulationError {
     ConvergenceFailure,
 }
 
+/// An error that can occur during the construction of a spline.
+#[derive(Error, Debug)]
+pub enum SplineError {
+    #[error("at least two interpolation points are required")]
+    TooFewPoints,
+    #[error("the abscissae of the interpolation points should be strictly increasing")]
+    UnorderedPoints,
+}
+
+/// An error that can occur during the computation of a prepartition.
+#[derive(Error, Debug)]
+pub enum PrepartitionError {
+    #[error("the nodes of the prepartition are not strictly monotonic")]
+    UnorderedNodes,
+}
+
+/// An error that can occur during the construction of a histogram function.
+#[derive(Error, Debug)]
+pub enum HistogramError {
+    #[error("the number of bin edges should exceed the number of bins by one")]
+    MismatchedLengths,
+    #[error("the bin edges should be finite and strictly increasing")]
+    UnorderedEdges,
+    #[error("the total count of the histogram should be strictly positive")]
+    ZeroCount,
+}
+
+/// An error that can occur during an adaptive quadrature.
+#[derive(Error, Debug)]
+pub enum QuadratureError {
+    #[error("the requested tolerance could not be reached within the maximum subdivision depth")]
+    MaxDepthExceeded,
+}
+
+/// An error reported when an envelope function is found to be below a
+/// probability density function.
+#[derive(Error, Debug)]
+#[error("the envelope is below the probability density function at x={x} (pdf: {pdf_value}, envelope: {envelope_value})")]
+pub struct EnvelopeValidationError<T: Float> {
+    /// The position at which the inequality is violated.
+    pub x: T,
+    /// The value of the probability density function at `x`.
+    pub pdf_value: T,
+    /// The value of the envelope function at `x`.
+    pub envelope_value: T,
+}
+
 /// Generates a partition by dividing approximately evenly the area under a
 /// function.
 ///
@@ -26,7 +75,52 @@ pub enum TabulationError {
 /// If argument `m` is zero, then the number of midpoint quadrature
 /// sub-intervals is set equal to the number of sub-intervals of the target
 /// partition.
+///
+/// The nodes are expected to be strictly monotonic, i.e. strictly increasing
+/// if `x0 < x1` and strictly decreasing if `x0 > x1`, which is only checked in
+/// debug builds. See [`try_midpoint_prepartition`] for a checked version.
+///
+/// [`try_midpoint_prepartition`]: fn.try_midpoint_prepartition.html
 pub fn midpoint_prepartition<P, T, F>(f: &F, x0: T, x1: T, m: usize) -> NodeArray<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    let x = compute_midpoint_prepartition(f, x0, x1, m);
+    debug_assert!(is_strictly_monotonic(&x));
+
+    x
+}
+
+/// Generates a partition by dividing approximately evenly the area under a
+/// function and checks that its nodes are strictly monotonic.
+///
+/// This is a checked version of [`midpoint_prepartition`], which returns an
+/// error if for instance the function vanishes over [`x0`, `x1`].
+///
+/// [`midpoint_prepartition`]: fn.midpoint_prepartition.html
+pub fn try_midpoint_prepartition<P, T, F>(
+    f: &F,
+    x0: T,
+    x1: T,
+    m: usize,
+) -> Result<NodeArray<P, T>, PrepartitionError>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    let x = compute_midpoint_prepartition(f, x0, x1, m);
+    if !is_strictly_monotonic(&x) {
+        return Err(PrepartitionError::UnorderedNodes);
+    }
+
+    Ok(x)
+}
+
+// Computes the midpoint prepartition without checking the result.
+fn compute_midpoint_prepartition<P, T, F>(f: &F, x0: T, x1: T, m: usize) -> NodeArray<P, T>
 where
     P: Partition<T>,
     T: Float,
@@ -55,8 +149,9 @@ where
             // Expected cumulated area from x0 to current partition.
             let a = ds * T::cast_usize(i);
 
-            // Integrate `f` from `x0` until `a` is smaller than `a_rect`.
-            while a_rect < a {
+            // Integrate `f` from `x0` until `a` is smaller than `a_rect`,
+            // skipping rectangles where `f` vanishes.
+            while (a_rect < a || y[rect] == T::ZERO) && rect + 1 < m {
                 rect += 1;
                 a_rect += y[rect];
                 x_rect += dx;
@@ -72,6 +167,221 @@ where
     x
 }
 
+// Checks that the nodes of a partition are strictly increasing or strictly
+// decreasing, depending on the sign of `x1 - x0`. This also rules out NaN
+// nodes.
+fn is_strictly_monotonic<P: Partition<T>, T: Float>(x: &NodeArray<P, T>) -> bool {
+    if x[0] < x[P::SIZE] {
+        (0..P::SIZE).all(|i| x[i] < x[i + 1])
+    } else {
+        (0..P::SIZE).all(|i| x[i] > x[i + 1])
+    }
+}
+
+/// Generates a partition with regularly spaced nodes.
+///
+/// The nodes are:
+///
+/// ```text
+/// x[i] = x0 + i (x1 - x0) / n
+/// ```
+///
+/// where `n` is the number of sub-intervals of the partition. This is usually
+/// a poor initial guess of the ETF partition compared to
+/// [`midpoint_prepartition`], except for nearly uniform functions, but being
+/// independent of the function it is convenient to test or debug the exact
+/// partitioning algorithm in isolation.
+///
+/// [`midpoint_prepartition`]: fn.midpoint_prepartition.html
+pub fn uniform_prepartition<P, T>(x0: T, x1: T) -> NodeArray<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+{
+    let n = P::SIZE;
+    let dx = (x1 - x0) / T::cast_usize(n);
+
+    let mut x = NodeArray::default();
+    for i in 1..n {
+        x[i] = x0 + dx * T::cast_usize(i);
+    }
+    x[0] = x0;
+    x[n] = x1;
+
+    x
+}
+
+/// Generates a partition with geometrically spaced nodes.
+///
+/// The result can be used as an initial guess of the ETF partition for the
+/// exact partitioning algorithm. The nodes are:
+///
+/// ```text
+/// x[i] = x0 (x1 / x0)^(i / n)
+/// ```
+///
+/// where `n` is the number of sub-intervals of the partition. This is typically
+/// a better initial guess than [`midpoint_prepartition`] for functions that vary
+/// over many orders of magnitude, such as gamma distributions with small shape
+/// parameters, log-normal or Pareto distributions.
+///
+/// Function `f` is not evaluated and is only taken for consistency with other
+/// prepartition functions.
+///
+/// # Panics
+///
+/// Panics unless `x0` and `x1` are finite, non-zero, of the same sign and
+/// such that `x0 < x1`.
+///
+/// [`midpoint_prepartition`]: fn.midpoint_prepartition.html
+pub fn logspace_prepartition<P, T, F>(_f: &F, x0: T, x1: T) -> NodeArray<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    assert!(
+        x0 < x1 && x1.is_finite() && x0.is_finite() && (x0 > T::ZERO || x1 < T::ZERO),
+        "the bounds should be finite, non-zero, of the same sign and increasing"
+    );
+
+    let n = P::SIZE;
+    let ln_ratio = (x1 / x0).ln();
+
+    let mut x = NodeArray::default();
+    for i in 1..n {
+        x[i] = x0 * (ln_ratio * T::cast_usize(i) / T::cast_usize(n)).exp();
+    }
+    x[0] = x0;
+    x[n] = x1;
+
+    x
+}
+
+/// Generates a partition with regularly spaced nodes between breakpoints.
+///
+/// The result can be used as an initial guess of the ETF partition for the
+/// exact partitioning algorithm.
+///
+/// The interval [`x0`, `x1`] is split at the specified breakpoints and each
+/// resulting segment is divided into the same number of regular
+/// sub-intervals, give or take one. This makes it possible to use a finer
+/// partition in regions where the function varies rapidly.
+///
+/// Function `f` is not evaluated and is only taken for consistency with other
+/// prepartition functions.
+///
+/// # Panics
+///
+/// Panics if the breakpoints are not strictly within (`x0`, `x1`) and in
+/// increasing order, or if there are more segments than partition
+/// sub-intervals.
+pub fn composite_prepartition<P, T, F>(_f: &F, x0: T, x1: T, breakpoints: &[T]) -> NodeArray<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    let n = P::SIZE;
+    let segment_count = breakpoints.len() + 1;
+    assert!(
+        segment_count <= n,
+        "there are more segments than partition sub-intervals"
+    );
+
+    let bounds: Vec<T> = std::iter::once(x0)
+        .chain(breakpoints.iter().cloned())
+        .chain(std::iter::once(x1))
+        .collect();
+    assert!(
+        bounds.windows(2).all(|w| w[0] < w[1]),
+        "the breakpoints should be strictly increasing and within (x0, x1)"
+    );
+
+    let mut x = NodeArray::default();
+    let mut i = 0;
+    for (segment, w) in bounds.windows(2).enumerate() {
+        // The remainder is spread over the first segments.
+        let m = n / segment_count + if segment < n % segment_count { 1 } else { 0 };
+        let dx = (w[1] - w[0]) / T::cast_usize(m);
+        for j in 0..m {
+            x[i + j] = w[0] + dx * T::cast_usize(j);
+        }
+        i += m;
+    }
+    x[n] = x1;
+
+    x
+}
+
+/// Generates a partition by dividing approximately evenly the area under a
+/// function using a quadrature at the zeros of a Hermite polynomial.
+///
+/// The result can be used as an initial guess of the ETF partition for the
+/// exact partitioning algorithm over [`mean - k std_dev`, `mean + k std_dev`].
+///
+/// This works like [`midpoint_prepartition`] except that function `f` is
+/// approximated by a rectangular midpoint quadrature over a non-regular
+/// partition whose inner nodes are the zeros of the Hermite polynomial of
+/// degree `n - 1`, where `n` is the number of sub-intervals of the partition.
+/// These zeros are linearly mapped such that their asymptotic bound
+/// `±√(2n - 1)` coincides with the boundaries of the partition. The quadrature
+/// is thus finer near the mean, where the area under normal-like functions is
+/// concentrated.
+///
+/// For normal functions, the resulting initial guess is of similar quality as
+/// that of [`midpoint_prepartition`] with the default number of quadrature
+/// sub-intervals. Since the quadrature is coarser in the tails, however, this
+/// prepartition is not advisable when `f` is not concentrated around `mean`.
+///
+/// [`midpoint_prepartition`]: fn.midpoint_prepartition.html
+pub fn hermite_prepartition<P, T, F>(f: &F, mean: T, std_dev: T, k: T) -> NodeArray<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    let n = P::SIZE;
+    let zeros = hermite_zeros::<T>(n - 1);
+    let scale = k * std_dev / T::cast_usize(2 * n - 1).sqrt();
+
+    // Quadrature nodes.
+    let q: Vec<T> = std::iter::once(mean - k * std_dev)
+        .chain(zeros.iter().map(|&z| mean + scale * z))
+        .chain(std::iter::once(mean + k * std_dev))
+        .collect();
+
+    // Rectangle areas of the midpoint quadrature.
+    let a_q: Vec<T> = q
+        .windows(2)
+        .map(|w| f.eval(T::ONE_HALF * (w[0] + w[1])) * (w[1] - w[0]))
+        .collect();
+
+    // Choose abscissae that evenly split the area under the curve.
+    let mut x = NodeArray::default();
+    let ds = a_q.iter().fold(T::ZERO, |s, &a_| a_ + s) / T::cast_usize(n);
+    let mut rect = 0;
+    let mut a_rect = a_q[0]; // cumulated rectangles area
+    for i in 1..n {
+        // Expected cumulated area from the lower bound to current partition.
+        let a = ds * T::cast_usize(i);
+
+        // Integrate `f` until `a` is smaller than `a_rect`, skipping
+        // rectangles where `f` vanishes.
+        while (a_rect < a || a_q[rect] == T::ZERO) && rect + 1 < n {
+            rect += 1;
+            a_rect += a_q[rect];
+        }
+
+        // Interpolate `x`.
+        x[i] = q[rect + 1] - (q[rect + 1] - q[rect]) * ((a_rect - a) / a_q[rect]);
+    }
+    x[0] = q[0];
+    x[n] = q[n];
+
+    x
+}
+
 /// Computes an ETF distribution initialization table using Newton's method.
 ///
 /// The multivariate Newton's method is used to compute a partition such that
@@ -101,9 +411,17 @@ where
 /// tolerances of the order of 0.001 or less will have no measurable impact on
 /// the sampling rate.
 ///
-/// The recommended  value for `relaxation` is 1, but a relaxation coefficient
-/// lower than 1 (resp. greater than 1) may be specified to improve convergence
-/// robustness (resp. convergence speed).
+/// Each Newton update is followed by a backtracking line search: the step is
+/// halved until the residual (the differences between the areas of
+/// neighboring rectangles) decreases, which prevents oscillations for
+/// badly-conditioned functions. If the residual cannot be decreased, a
+/// `TabulationError` is returned without waiting for the maximum number of
+/// iterations.
+///
+/// The `relaxation` coefficient is the initial step length of the line search.
+/// The recommended value is 1, but a relaxation coefficient lower than 1
+/// (resp. greater than 1) may be specified to improve convergence robustness
+/// (resp. convergence speed).
 pub fn newton_tabulation<P, T, F, DF>(
     f: &F,
     df: &DF,
@@ -118,6 +436,189 @@ where
     T: Float,
     F: UnivariateFn<T>,
     DF: UnivariateFn<T>,
+{
+    newton_tabulation_with_progress(
+        f,
+        df,
+        None::<&DF>,
+        x_init,
+        x_extrema,
+        tolerance,
+        relaxation,
+        max_iter,
+        |_, _| {},
+    )
+}
+
+/// Builder for an ETF distribution initialization table computed with
+/// Newton's method.
+///
+/// This is an alternative to [`newton_tabulation`] which makes it possible to
+/// override only some of the tabulation parameters and to monitor the progress
+/// of long-running tabulations.
+///
+/// Unless overridden, the tolerance is set to 1e-4, the relaxation
+/// coefficient to 1 and the maximum number of iterations to 50.
+///
+/// [`newton_tabulation`]: fn.newton_tabulation.html
+pub struct TabulationBuilder<'a, P, T, F, DF>
+where
+    P: Partition<T>,
+    T: Float,
+{
+    f: &'a F,
+    df: &'a DF,
+    x_init: &'a NodeArray<P, T>,
+    x_extrema: &'a [T],
+    tolerance: T,
+    relaxation: T,
+    max_iter: u32,
+    progress: Option<Box<dyn FnMut(u32, T) + 'a>>,
+}
+
+impl<'a, P, T, F, DF> TabulationBuilder<'a, P, T, F, DF>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+    DF: UnivariateFn<T>,
+{
+    /// Creates a tabulation builder for function `f`, its derivative `df`, the
+    /// initial partition `x_init` and the ordered sequence `x_extrema` of the
+    /// extrema of `f`.
+    ///
+    /// See [`newton_tabulation`] for the meaning of the arguments.
+    ///
+    /// [`newton_tabulation`]: fn.newton_tabulation.html
+    pub fn new(f: &'a F, df: &'a DF, x_init: &'a NodeArray<P, T>, x_extrema: &'a [T]) -> Self {
+        Self {
+            f,
+            df,
+            x_init,
+            x_extrema,
+            tolerance: T::from(1.0e-4),
+            relaxation: T::ONE,
+            max_iter: 50,
+            progress: None,
+        }
+    }
+
+    /// Sets the relative tolerance on the rectangle areas.
+    pub fn tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the relaxation coefficient of the Newton iterations.
+    pub fn relaxation(mut self, relaxation: T) -> Self {
+        self.relaxation = relaxation;
+        self
+    }
+
+    /// Sets the maximum number of Newton iterations.
+    pub fn max_iter(mut self, max_iter: u32) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets a callback invoked once per iteration.
+    ///
+    /// The callback receives the iteration number and the difference between
+    /// the areas of the largest and smallest rectangles relative to their
+    /// average area, which is the quantity compared to the tolerance.
+    pub fn progress<C: FnMut(u32, T) + 'a>(mut self, progress: C) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Computes the table.
+    pub fn build(self) -> Result<InitTable<P, T>, TabulationError> {
+        let mut progress = self.progress;
+
+        newton_tabulation_with_progress(
+            self.f,
+            self.df,
+            None::<&DF>,
+            self.x_init,
+            self.x_extrema,
+            self.tolerance,
+            self.relaxation,
+            self.max_iter,
+            |iter, error| {
+                if let Some(progress) = progress.as_mut() {
+                    progress(iter, error);
+                }
+            },
+        )
+    }
+}
+
+/// Computes an ETF distribution initialization table using a second-order
+/// Newton's method.
+///
+/// This is a variant of [`newton_tabulation`] which additionally requires the
+/// second derivative `ddf` of function `f`. At each iteration, the Newton
+/// update is complemented by a correction which accounts for the second-order
+/// variation of the rectangle areas (Chebyshev's method). This makes the
+/// convergence cubic rather than quadratic close to the solution, which
+/// typically saves a few iterations at the cost of an additional evaluation of
+/// `ddf` per node and per iteration.
+///
+/// See [`newton_tabulation`] for the meaning of the other arguments.
+///
+/// [`newton_tabulation`]: fn.newton_tabulation.html
+#[allow(clippy::too_many_arguments)]
+pub fn second_order_newton_tabulation<P, T, F, DF, DDF>(
+    f: &F,
+    df: &DF,
+    ddf: &DDF,
+    x_init: &NodeArray<P, T>,
+    x_extrema: &[T],
+    tolerance: T,
+    relaxation: T,
+    max_iter: u32,
+) -> Result<InitTable<P, T>, TabulationError>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+    DF: UnivariateFn<T>,
+    DDF: UnivariateFn<T>,
+{
+    newton_tabulation_with_progress(
+        f,
+        df,
+        Some(ddf),
+        x_init,
+        x_extrema,
+        tolerance,
+        relaxation,
+        max_iter,
+        |_, _| {},
+    )
+}
+
+// Implementation of `newton_tabulation` and `second_order_newton_tabulation`
+// with a progress callback.
+#[allow(clippy::too_many_arguments)]
+fn newton_tabulation_with_progress<P, T, F, DF, DDF, C>(
+    f: &F,
+    df: &DF,
+    ddf: Option<&DDF>,
+    x_init: &NodeArray<P, T>,
+    x_extrema: &[T],
+    tolerance: T,
+    relaxation: T,
+    max_iter: u32,
+    mut progress: C,
+) -> Result<InitTable<P, T>, TabulationError>
+where
+    P: Partition<T>,
+    T: Float,
+    F: UnivariateFn<T>,
+    DF: UnivariateFn<T>,
+    DDF: UnivariateFn<T>,
+    C: FnMut(u32, T),
 {
     // Initialize the quadrature table partition with the initial partition.
     let mut table = InitTable::<P, T> {
@@ -137,6 +638,19 @@ where
     let mut ds_dxl = vec![T::ZERO; n - 1];
     let mut ds_dxr = vec![T::ZERO; n - 1];
 
+    // Additional vectors for the second-order correction.
+    let mut d2y_dx2 = vec![T::ZERO; n + 1];
+    let mut d2ysup_dxl2 = vec![T::ZERO; n];
+    let mut d2ysup_dxr2 = vec![T::ZERO; n];
+    let mut ds_dxc_copy = vec![T::ZERO; n - 1];
+    let mut minus_q = vec![T::ZERO; n - 1];
+    let mut dx_correction = vec![T::ZERO; n - 1];
+
+    // Vectors for the line search.
+    let mut x_trial = vec![T::ZERO; n + 1];
+    let mut y_trial = vec![T::ZERO; n + 1];
+    let mut ysup_trial = vec![T::ZERO; n];
+
     let y_extrema: Vec<T> = x_extrema.iter().cloned().map(|x| f.eval(x)).collect();
     // Make a vector of the (x,y) tuples of all extrema that are actually
     // wihtin the partition.
@@ -147,24 +661,33 @@ where
         .filter(|&(x_e, _)| (x_e > table.x[0]) && (x_e < table.x[n]))
         .collect();
 
-    // Boundary values are constants.
-    y[0] = f.eval(table.x[0]);
-    y[n] = f.eval(table.x[n]);
+    // Initial node values; boundary values are constants.
+    for (i, y_i) in y.iter_mut().enumerate() {
+        *y_i = f.eval(table.x[i]);
+    }
     dy_dx[0] = T::ZERO;
     dy_dx[n] = T::ZERO;
+    x_trial[0] = table.x[0];
+    x_trial[n] = table.x[n];
+    y_trial[0] = y[0];
+    y_trial[n] = y[n];
 
     // Loop until convergence is achieved or the maximum number of iteration is reached.
     let mut loop_iter = 0..max_iter;
+    let mut iter = 0;
     loop {
         // Convenient aliases.
         let x = &mut table.x;
         let yinf = &mut table.yinf;
         let ysup = &mut table.ysup;
 
-        // Update inner nodes values.
+        // Update inner nodes derivatives (the values of `f` are computed
+        // during the line search).
         for i in 1..n {
-            y[i] = f.eval(x[i]);
             dy_dx[i] = df.eval(x[i]);
+            if let Some(ddf) = ddf {
+                d2y_dx2[i] = ddf.eval(x[i]);
+            }
         }
 
         // Determine the supremum fsup of y within [x[i], x[i+1]),
@@ -176,14 +699,16 @@ where
         let mut min_area = T::INFINITY;
         let mut sum_area = T::ZERO;
         for i in 0..n {
-            let (ysup_, dysup_dxl_, dysup_dxr_) = if y[i] > y[i + 1] {
-                (y[i], dy_dx[i], T::ZERO)
+            let (ysup_, dysup_dxl_, dysup_dxr_, d2ysup_dxl2_, d2ysup_dxr2_) = if y[i] > y[i + 1] {
+                (y[i], dy_dx[i], T::ZERO, d2y_dx2[i], T::ZERO)
             } else {
-                (y[i + 1], T::ZERO, dy_dx[i + 1])
+                (y[i + 1], T::ZERO, dy_dx[i + 1], T::ZERO, d2y_dx2[i + 1])
             };
             ysup[i] = ysup_;
             dysup_dxl[i] = dysup_dxl_;
             dysup_dxr[i] = dysup_dxr_;
+            d2ysup_dxl2[i] = d2ysup_dxl2_;
+            d2ysup_dxr2[i] = d2ysup_dxr2_;
 
             // Check if there are suprema between x[i] and x[i+1] and
             // advance the extrema iterator until the current extremum no
@@ -194,6 +719,8 @@ where
                         ysup[i] = y_e;
                         dysup_dxl[i] = T::ZERO;
                         dysup_dxr[i] = T::ZERO;
+                        d2ysup_dxl2[i] = T::ZERO;
+                        d2ysup_dxr2[i] = T::ZERO;
                     }
                     extremum = extrema_iter.next();
                 } else {
@@ -210,6 +737,9 @@ where
         // Return the table if convergence was achieved.
         let mean_area = sum_area / T::cast_usize(n);
 
+        progress(iter, (max_area - min_area) / mean_area);
+        iter += 1;
+
         if (max_area - min_area) < tolerance * mean_area {
             // At this point the areas are likely to differ slightly due to
             // roundoff errors, which would introduce some bias when the
@@ -265,6 +795,8 @@ where
             ds_dxr[i] = ysup[i + 1] + (x[i + 2] - x[i + 1]) * dysup_dxr[i + 1];
         }
 
+        let residual = minus_s.iter().fold(T::ZERO, |r, &s| r + s * s);
+
         // Solve the tri-diagonal system S + (dS/dX)*dX = 0 with:
         //         | ds0/dx1 ds0/dx2    0     ...                    0     |
         //         | ds1/dx1 ds1/dx2 ds1/dx3    0     ...            0     |
@@ -277,11 +809,49 @@ where
         //      | dx1     |         | minus_s0     |
         // dX = | ...     |    -S = | ...    |
         //      | dx(n-1) |         | minus_s(n-2) |
+        if ddf.is_some() {
+            ds_dxc_copy.copy_from_slice(&ds_dxc);
+        }
         solve_tma(&ds_dxl, &mut ds_dxc, &ds_dxr, &mut minus_s, &mut dx);
 
-        // Improve robustness by constraining updated positions within
-        // the bounds set by former neighbors positions.
-        {
+        // Second-order correction.
+        //
+        // With the second-order term Q of the variation of each rectangle area,
+        // the variation of s[i] reads Q[i+1] - Q[i] + (dS/dX)*dX. The
+        // correction dX' to the first-order solution dX is thus given by the
+        // solution of (dS/dX)*dX' = Q[i] - Q[i+1], with Q evaluated for dX.
+        if ddf.is_some() {
+            let one_half = T::ONE / (T::ONE + T::ONE);
+            let q = |i: usize| {
+                let dxl = if i == 0 { T::ZERO } else { dx[i - 1] };
+                let dxr = if i == n - 1 { T::ZERO } else { dx[i] };
+                let w = x[i + 1] - x[i];
+
+                one_half * w * (d2ysup_dxl2[i] * dxl * dxl + d2ysup_dxr2[i] * dxr * dxr)
+                    + (dysup_dxl[i] * dxl + dysup_dxr[i] * dxr) * (dxr - dxl)
+            };
+            for (i, minus_q_i) in minus_q.iter_mut().enumerate() {
+                *minus_q_i = q(i) - q(i + 1);
+            }
+            solve_tma(
+                &ds_dxl,
+                &mut ds_dxc_copy,
+                &ds_dxr,
+                &mut minus_q,
+                &mut dx_correction,
+            );
+            for (dx_i, &correction) in dx.iter_mut().zip(dx_correction.iter()) {
+                *dx_i += correction;
+            }
+        }
+
+        // Backtracking line search: the step is halved until the residual
+        // decreases. Convergence has failed if the step becomes negligible.
+        let min_step = T::from(1.0e-10);
+        let mut step = relaxation;
+        loop {
+            // Improve robustness by constraining updated positions within
+            // the bounds set by former neighbors positions.
             for i in 1..n {
                 let (xmin, xmax) = if x[i + 1] > x[i - 1] {
                     (x[i - 1], x[i + 1])
@@ -289,13 +859,175 @@ where
                     (x[i + 1], x[i - 1])
                 };
 
-                let mut xi = x[i] + relaxation * dx[i - 1];
+                let mut xi = x[i] + step * dx[i - 1];
                 xi = xi.min(xmax);
                 xi = xi.max(xmin);
-                x[i] = xi;
+                x_trial[i] = xi;
+            }
+
+            let trial_residual =
+                squared_area_residual(f, &x_trial, &extrema, &mut y_trial, &mut ysup_trial);
+            if trial_residual < residual {
+                break;
+            }
+            step = T::ONE_HALF * step;
+            if step < min_step {
+                return Err(TabulationError::ConvergenceFailure);
+            }
+        }
+        for i in 1..n {
+            x[i] = x_trial[i];
+        }
+        std::mem::swap(&mut y, &mut y_trial);
+    }
+}
+
+// Computes the sum of the squared differences between the areas of
+// neighboring rectangles for the partition `x`.
+//
+// The values of `f` at the inner nodes are stored in `y`, whose boundary
+// values must be already set. Vector `ysup` is a work buffer.
+fn squared_area_residual<T, F>(f: &F, x: &[T], extrema: &[(T, T)], y: &mut [T], ysup: &mut [T]) -> T
+where
+    T: Float,
+    F: UnivariateFn<T>,
+{
+    let n = ysup.len();
+    for i in 1..n {
+        y[i] = f.eval(x[i]);
+    }
+
+    let mut extrema_iter = extrema.iter();
+    let mut extremum = extrema_iter.next();
+    for i in 0..n {
+        ysup[i] = if y[i] > y[i + 1] { y[i] } else { y[i + 1] };
+        while let Some(&(x_e, y_e)) = extremum {
+            if (x_e > x[i]) != (x_e > x[i + 1]) {
+                if y_e > ysup[i] {
+                    ysup[i] = y_e;
+                }
+                extremum = extrema_iter.next();
+            } else {
+                break;
             }
         }
     }
+
+    (0..(n - 1)).fold(T::ZERO, |r, i| {
+        let s = ysup[i] * (x[i + 1] - x[i]) - ysup[i + 1] * (x[i + 2] - x[i + 1]);
+        r + s * s
+    })
+}
+
+/// Checks that an envelope function is above a probability density function.
+///
+/// Both functions are evaluated at `n_points` regularly spaced positions
+/// between `x_start` and `x_end` (inclusive) and an error is returned for the
+/// first position where `pdf(x) > envelope(x)`.
+///
+/// The validation is only as good as the density of the grid and its cost is
+/// proportional to `n_points`, so it is meant to be used in tests or in debug
+/// builds rather than in release code paths. [`WeibullEnvelope::new`], for
+/// instance, only calls it when debug assertions are enabled.
+///
+/// [`WeibullEnvelope::new`]: struct.WeibullEnvelope.html#method.new
+pub fn validate_envelope<T, F, E>(
+    pdf: &F,
+    envelope: &E,
+    x_start: T,
+    x_end: T,
+    n_points: usize,
+) -> Result<(), EnvelopeValidationError<T>>
+where
+    T: Float,
+    F: UnivariateFn<T>,
+    E: UnivariateFn<T>,
+{
+    let dx = if n_points > 1 {
+        (x_end - x_start) / T::cast_usize(n_points - 1)
+    } else {
+        T::ZERO
+    };
+
+    for i in 0..n_points {
+        let x = x_start + T::cast_usize(i) * dx;
+        let pdf_value = pdf.eval(x);
+        let envelope_value = envelope.eval(x);
+        if pdf_value > envelope_value {
+            return Err(EnvelopeValidationError {
+                x,
+                pdf_value,
+                envelope_value,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes the area under a function over [`x0`, `x1`].
+///
+/// The integral is estimated with the composite Simpson's rule over `n`
+/// regular sub-intervals, which requires `2n+1` function evaluations. A
+/// zero value of `n` is treated as `1`.
+///
+/// This is meant for validation purposes, e.g. to check the tail area passed
+/// to [`DistAnyTailed::new`] or to compute the normalization constant of a
+/// custom probability density function.
+///
+/// [`DistAnyTailed::new`]: ../struct.DistAnyTailed.html#method.new
+pub fn pdf_area<T: Float, F: UnivariateFn<T>>(f: &F, x0: T, x1: T, n: usize) -> T {
+    let n = n.max(1);
+    let h = (x1 - x0) / T::cast_usize(n);
+
+    let mut sum = f.eval(x0) + f.eval(x1);
+    for i in 0..n {
+        let x = x0 + T::cast_usize(i) * h;
+        sum += T::from(4.0) * f.eval(x + T::ONE_HALF * h);
+        if i != 0 {
+            sum += T::TWO * f.eval(x);
+        }
+    }
+
+    sum * h / T::from(6.0)
+}
+
+/// Computes the area under a function over [`x0`, `x1`] with an adaptive
+/// Simpson's rule.
+///
+/// Sub-intervals are recursively halved until the estimated error of the
+/// area over each sub-interval is commensurate with the relative tolerance.
+/// `QuadratureError` is returned if the subdivision depth exceeds 50 before
+/// the requested tolerance is reached, which typically occurs for
+/// non-integrable singularities.
+pub fn pdf_area_adaptive<T: Float, F: UnivariateFn<T>>(
+    f: &F,
+    x0: T,
+    x1: T,
+    tolerance: T,
+) -> Result<T, QuadratureError> {
+    const MAX_DEPTH: u32 = 50;
+
+    let y0 = f.eval(x0);
+    let y1 = f.eval(x1);
+    let x_mid = T::ONE_HALF * (x0 + x1);
+    let y_mid = f.eval(x_mid);
+    let area = (x1 - x0) * (y0 + T::from(4.0) * y_mid + y1) / T::from(6.0);
+
+    // The absolute tolerance is determined from a coarse estimate of the area
+    // over a few sub-intervals so as to reduce the risk of a spuriously small
+    // initial estimate.
+    let abs_tolerance = tolerance * pdf_area(f, x0, x1, 8).abs();
+
+    adaptive_simpson(
+        f,
+        (x0, y0),
+        (x_mid, y_mid),
+        (x1, y1),
+        area,
+        abs_tolerance,
+        MAX_DEPTH,
+    )
 }
 
 /// Distribution envelope based on a shifted Weibull distribution tail.
@@ -327,6 +1059,10 @@ where
 /// The weight `w` controls the vertical scaling of the function relative to the
 /// normalized Weibull PDF (`w=1`).
 ///
+/// The envelope function itself can be evaluated through the `UnivariateFn`
+/// implementation, e.g. to check it with [`validate_envelope`].
+///
+/// [`validate_envelope`]: fn.validate_envelope.html
 #[derive(Copy, Clone, Debug)]
 pub struct WeibullEnvelope<T, F> {
     a: T,
@@ -349,8 +1085,14 @@ impl<T: Float, F: UnivariateFn<T>> WeibullEnvelope<T, F> {
     /// all `x` greater than the cut-in tail position if the shape parameter is
     /// positive, or for all `x` lesser than the cut-in tail position if the
     /// shape parameter is negative.
+    ///
+    /// When debug assertions are enabled, this is checked with
+    /// [`validate_envelope`] over the range where the envelope is not
+    /// negligible and a panic occurs if the envelope is below `pdf`.
+    ///
+    /// [`validate_envelope`]: fn.validate_envelope.html
     pub fn new(weight: T, scale: T, shape: T, location: T, cut_in: T, pdf: F) -> Self {
-        Self {
+        let envelope = Self {
             a: scale,
             inv_a: T::ONE / scale,
             b: shape,
@@ -360,14 +1102,40 @@ impl<T: Float, F: UnivariateFn<T>> WeibullEnvelope<T, F> {
             s: weight * T::abs(scale / shape),
             alpha: T::powf((cut_in - location) / shape, scale),
             f: pdf,
+        };
+
+        // The envelope decays as `exp(-((x-c)/b)^a)`, so the check is limited
+        // to positions where `((x-c)/b)^a` does not exceed its value at the
+        // cut-in by more than 40.
+        #[cfg(debug_assertions)]
+        {
+            let x_end = location + shape * T::powf(envelope.alpha + T::from(40.0), envelope.inv_a);
+            if let Err(err) = validate_envelope(&envelope.f, &envelope, cut_in, x_end, 1000) {
+                panic!("invalid Weibull envelope: {}", err);
+            }
         }
+
+        envelope
     }
 
     /// Computes the area under the envelope.
     pub fn area(&self) -> T {
         let z0 = T::powf((self.x0 - self.c) * self.inv_b, self.a);
 
-        self.s * T::exp(-z0) * self.inv_a * self.b
+        self.s * T::exp(-z0) * self.inv_a * self.b.abs()
+    }
+}
+
+impl<T: Float, F> UnivariateFn<T> for WeibullEnvelope<T, F> {
+    #[inline]
+    fn eval(&self, x: T) -> T {
+        if (x - self.x0) * self.inv_b < T::ZERO {
+            return T::ZERO;
+        }
+        let x_scaled = (x - self.c) * self.inv_b;
+        let z = T::powf(x_scaled, self.a - T::ONE);
+
+        self.s * z * T::exp(-x_scaled * z)
     }
 }
 
@@ -388,6 +1156,525 @@ impl<T: Float, F: UnivariateFn<T>> TryDistribution<T> for WeibullEnvelope<T, F>
     }
 }
 
+/// Envelope made of two envelopes with disjoint supports.
+///
+/// Each sample is drawn from one of the two envelopes, selected with a
+/// probability proportional to its area. This is typically used to combine a
+/// left tail envelope with a right tail envelope.
+///
+/// The supports of the envelopes must be disjoint. Each envelope performs its
+/// own acceptance-rejection test, so the density of the accepted samples is
+/// the sum of the densities accepted by each envelope: over a region where two
+/// envelopes overlap, the probability density function would be counted
+/// twice.
+///
+/// Additional envelopes can be appended with the `+` operator applied to an
+/// `(envelope, area)` tuple:
+///
+/// ```ignore
+/// let tail = SumEnvelope::new(left, left_area, right, right_area) + (center, center_area);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct SumEnvelope<T, E1, E2> {
+    e1: E1,
+    e2: E2,
+    weight1: T,
+    area: T,
+}
+
+impl<T: Float, E1, E2> SumEnvelope<T, E1, E2> {
+    /// Creates an envelope from two envelopes and their respective areas.
+    pub fn new(e1: E1, area1: T, e2: E2, area2: T) -> Self {
+        let area = area1 + area2;
+
+        Self {
+            e1,
+            e2,
+            weight1: area1 / area,
+            area,
+        }
+    }
+
+    /// Returns the total area under the envelope.
+    pub fn area(&self) -> T {
+        self.area
+    }
+}
+
+impl<T: Float, E1, E2, E3> Add<(E3, T)> for SumEnvelope<T, E1, E2> {
+    type Output = SumEnvelope<T, Self, E3>;
+
+    fn add(self, (e3, area3): (E3, T)) -> Self::Output {
+        let area = self.area;
+
+        SumEnvelope::new(self, area, e3, area3)
+    }
+}
+
+impl<T, E1, E2> TryDistribution<T> for SumEnvelope<T, E1, E2>
+where
+    T: Float,
+    E1: TryDistribution<T>,
+    E2: TryDistribution<T>,
+{
+    #[inline(always)]
+    fn try_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        if T::gen(rng) < self.weight1 {
+            self.e1.try_sample(rng)
+        } else {
+            self.e2.try_sample(rng)
+        }
+    }
+}
+
+/// Polynomial function.
+///
+/// The polynomial is defined by its coefficients in ascending degree order and
+/// is evaluated with Horner's method:
+///
+/// ```text
+/// p(x) = c₀ + c₁ x + c₂ x² + ... + cₙ xⁿ
+/// ```
+///
+/// Since the evaluation involves no division, the default implementation of
+/// the wedge acceptance-rejection test `a * p(x) > b` is already optimal and
+/// is not overridden.
+#[derive(Clone, Debug)]
+pub struct Polynomial<T> {
+    coefficients: Vec<T>,
+}
+
+impl<T: Float> Polynomial<T> {
+    /// Creates a polynomial from its coefficients in ascending degree order.
+    pub fn new(coefficients: Vec<T>) -> Self {
+        Self { coefficients }
+    }
+
+    /// Returns the coefficients in ascending degree order.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| T::cast_usize(i) * c)
+            .collect();
+
+        Self { coefficients }
+    }
+}
+
+impl<T: Float> UnivariateFn<T> for Polynomial<T> {
+    #[inline]
+    fn eval(&self, x: T) -> T {
+        self.coefficients.iter().rev().fold(T::ZERO, |y, &c| {
+            if cfg!(feature = "fma") {
+                y.mul_add(x, c)
+            } else {
+                y * x + c
+            }
+        })
+    }
+}
+
+/// Natural cubic spline function.
+///
+/// The spline interpolates a set of `(x, y)` points with strictly increasing
+/// abscissae and has a vanishing second derivative at both end points. This
+/// makes it possible to tabulate functions with no closed-form expression such
+/// as PDFs computed by numerical integration.
+///
+/// The function is evaluated as a piecewise polynomial, the segment containing
+/// `x` being found by binary search. Outside the interpolation range, the
+/// polynomial of the closest segment is used.
+#[derive(Clone, Debug)]
+pub struct SplineUnivariateFn<T> {
+    x: Vec<T>,
+    coefficients: Vec<[T; 4]>, // coefficients of (x - x[i])^k in ascending degree order
+}
+
+impl<T: Float> SplineUnivariateFn<T> {
+    /// Creates a natural cubic spline interpolating the specified points.
+    pub fn new(points: &[(T, T)]) -> Result<Self, SplineError> {
+        let n = points.len();
+        if n < 2 {
+            return Err(SplineError::TooFewPoints);
+        }
+        // NaN abscissae are rejected as well.
+        if points
+            .windows(2)
+            .any(|p| p[1].0.partial_cmp(&p[0].0) != Some(Ordering::Greater))
+        {
+            return Err(SplineError::UnorderedPoints);
+        }
+
+        let x: Vec<T> = points.iter().map(|p| p.0).collect();
+        let y: Vec<T> = points.iter().map(|p| p.1).collect();
+        let h: Vec<T> = x.windows(2).map(|x| x[1] - x[0]).collect();
+        let slope: Vec<T> = (0..n - 1).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+        // Solve the tri-diagonal system for the second derivatives at the
+        // inner nodes, the second derivatives at the end points being zero.
+        let mut d2y = vec![T::ZERO; n];
+        if n > 2 {
+            let m = n - 2;
+            let six = T::cast_u32(6);
+            let a: Vec<T> = (0..m).map(|i| h[i]).collect();
+            let mut b: Vec<T> = (0..m).map(|i| T::TWO * (h[i] + h[i + 1])).collect();
+            let c: Vec<T> = (0..m).map(|i| h[i + 1]).collect();
+            let mut rhs: Vec<T> = (0..m).map(|i| six * (slope[i + 1] - slope[i])).collect();
+            solve_tma(&a, &mut b, &c, &mut rhs, &mut d2y[1..n - 1]);
+        }
+
+        let coefficients = (0..n - 1)
+            .map(|i| {
+                let sixth_h = h[i] / T::cast_u32(6);
+                [
+                    y[i],
+                    slope[i] - sixth_h * (T::TWO * d2y[i] + d2y[i + 1]),
+                    T::ONE_HALF * d2y[i],
+                    (d2y[i + 1] - d2y[i]) / (T::cast_u32(6) * h[i]),
+                ]
+            })
+            .collect();
+
+        Ok(Self { x, coefficients })
+    }
+
+    /// Returns the derivative of the spline.
+    ///
+    /// The derivative is a piecewise quadratic function over the same segments.
+    pub fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|c| [c[1], T::TWO * c[2], T::cast_u32(3) * c[3], T::ZERO])
+            .collect();
+
+        Self {
+            x: self.x.clone(),
+            coefficients,
+        }
+    }
+}
+
+impl<T: Float> UnivariateFn<T> for SplineUnivariateFn<T> {
+    #[inline]
+    fn eval(&self, x: T) -> T {
+        let n = self.x.len();
+        let i = self.x[1..n - 1].partition_point(|&x_i| x_i <= x);
+        let c = &self.coefficients[i];
+        let t = x - self.x[i];
+
+        ((c[3] * t + c[2]) * t + c[1]) * t + c[0]
+    }
+}
+
+/// Function with removable singularities.
+///
+/// This wrapper evaluates to the limit of the function at each singularity
+/// `x_s` for all `x` such that `|x - x_s| < epsilon`, and to the wrapped
+/// function elsewhere. This makes it possible to tabulate functions such as
+/// `sin(x)/x` which evaluate to `0/0` at some node.
+///
+/// The derivative of the function may need to be regularized as well, in
+/// which case it can be wrapped in a separate instance.
+#[derive(Clone, Debug)]
+pub struct SingularPdf<T, F> {
+    f: F,
+    singularities: Vec<(T, T)>,
+    epsilon: T,
+}
+
+impl<T: Float, F: UnivariateFn<T>> SingularPdf<T, F> {
+    /// Creates the wrapper from a function, a list of `(x_s, limit)` pairs
+    /// where `limit` is the limit of the function at `x_s`, and the radius
+    /// of the neighborhood of each singularity where the limit is used.
+    pub fn new(f: F, singularities: Vec<(T, T)>, epsilon: T) -> Self {
+        Self {
+            f,
+            singularities,
+            epsilon,
+        }
+    }
+}
+
+impl<T: Float, F: UnivariateFn<T>> UnivariateFn<T> for SingularPdf<T, F> {
+    #[inline]
+    fn eval(&self, x: T) -> T {
+        for &(x_s, limit) in &self.singularities {
+            if (x - x_s).abs() < self.epsilon {
+                return limit;
+            }
+        }
+
+        self.f.eval(x)
+    }
+}
+
+/// Normalized probability density function of a histogram.
+///
+/// The function is constant over each bin, where it is equal to the bin count
+/// divided by the total count and by the bin width, and vanishes outside the
+/// range of the histogram.
+#[derive(Clone, Debug)]
+pub struct HistogramPdf<T> {
+    edges: Vec<T>,
+    densities: Vec<T>,
+    cdf: Vec<T>, // cumulative probability at each edge
+}
+
+impl<T: Float> HistogramPdf<T> {
+    /// Creates the function from the bin edges and the bin counts.
+    ///
+    /// The number of bin edges must exceed the number of bins by one and the
+    /// bin edges must be strictly increasing. Empty bins are allowed but the
+    /// total count must be strictly positive.
+    pub fn new(bin_edges: &[T], counts: &[u64]) -> Result<Self, HistogramError> {
+        if bin_edges.len() != counts.len() + 1 {
+            return Err(HistogramError::MismatchedLengths);
+        }
+        if !bin_edges.iter().all(|x| x.is_finite()) || bin_edges.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(HistogramError::UnorderedEdges);
+        }
+        let total_count: u64 = counts.iter().sum();
+        if total_count == 0 {
+            return Err(HistogramError::ZeroCount);
+        }
+
+        let total_count = cast_u64::<T>(total_count);
+        let mut densities = Vec::with_capacity(counts.len());
+        let mut cdf = Vec::with_capacity(bin_edges.len());
+        let mut cumulative_count = 0;
+        cdf.push(T::ZERO);
+        for (w, &count) in bin_edges.windows(2).zip(counts) {
+            densities.push(cast_u64::<T>(count) / (total_count * (w[1] - w[0])));
+            cumulative_count += count;
+            cdf.push(cast_u64::<T>(cumulative_count) / total_count);
+        }
+
+        Ok(Self {
+            edges: bin_edges.to_vec(),
+            densities,
+            cdf,
+        })
+    }
+
+    // Returns the index of the bin such that `x[j] <= x < x[j+1]`, clamped
+    // to the range of bins.
+    fn bin(&self, x: T) -> usize {
+        self.edges
+            .partition_point(|&e| e <= x)
+            .saturating_sub(1)
+            .min(self.densities.len() - 1)
+    }
+
+    // Returns the index of the bin such that `x[j] < x <= x[j+1]`, clamped
+    // to the range of bins.
+    fn left_bin(&self, x: T) -> usize {
+        self.edges
+            .partition_point(|&e| e < x)
+            .saturating_sub(1)
+            .min(self.densities.len() - 1)
+    }
+}
+
+// Converts a `u64` to a float without going through `usize`, which may be
+// narrower than `u64`.
+fn cast_u64<T: Float>(u: u64) -> T {
+    let hi = T::cast_u32((u >> 32) as u32);
+    let lo = T::cast_u32(u as u32);
+
+    hi * T::from(4294967296.0f32) + lo
+}
+
+impl<T: Float> UnivariateFn<T> for HistogramPdf<T> {
+    #[inline]
+    fn eval(&self, x: T) -> T {
+        let n = self.densities.len();
+        if x >= self.edges[0] && x < self.edges[n] {
+            self.densities[self.bin(x)]
+        } else {
+            T::ZERO
+        }
+    }
+}
+
+/// Computes the ETF table of a histogram.
+///
+/// Since the function is piecewise constant, no iteration is needed: the
+/// nodes are placed at the quantiles `i / n` of the histogram distribution,
+/// where `n` is the number of sub-intervals of the partition, irrespective of
+/// the number of bins. The lower and upper bounds of the function over each
+/// sub-interval are then its minimum and maximum over the bins it overlaps,
+/// and the upper bounds are finally raised so that all rectangles have the
+/// same area. Leading and trailing empty bins are left out of the partition.
+///
+/// Several nodes may coincide when a bin is too narrow to be resolved at the
+/// floating point precision. The lower bound of such zero-width sub-intervals
+/// is set to zero and their upper bound is not raised, so they are always
+/// rejected and do not take part in the sampling.
+pub fn histogram_tabulation<P, T>(pdf: &HistogramPdf<T>) -> InitTable<P, T>
+where
+    P: Partition<T>,
+    T: Float,
+{
+    let n = P::SIZE;
+    let m = pdf.densities.len();
+    let first_bin = pdf.densities.iter().position(|&d| d > T::ZERO).unwrap();
+    let last_bin = pdf.densities.iter().rposition(|&d| d > T::ZERO).unwrap();
+
+    let mut table = InitTable::<P, T>::default();
+    let x = &mut table.x;
+    x[0] = pdf.edges[first_bin];
+    x[n] = pdf.edges[last_bin + 1];
+    for i in 1..n {
+        let p = T::cast_usize(i) / T::cast_usize(n);
+        // The bin is such that `cdf[j] <= p < cdf[j+1]`, so it is not empty.
+        let j = (pdf.cdf.partition_point(|&c| c <= p) - 1).min(m - 1);
+        let x_i = pdf.edges[j] + (p - pdf.cdf[j]) / pdf.densities[j];
+        x[i] = x_i.max(pdf.edges[j]).min(pdf.edges[j + 1]);
+    }
+
+    // Bounds of the function over each sub-interval.
+    let mut max_area = T::ZERO;
+    for i in 0..n {
+        let first = pdf.bin(table.x[i]);
+        let last = pdf.left_bin(table.x[i + 1]).max(first);
+        let densities = &pdf.densities[first..=last];
+        table.yinf[i] = densities.iter().fold(T::INFINITY, |a, &b| a.min(b));
+        table.ysup[i] = densities.iter().fold(T::ZERO, |a, &b| a.max(b));
+        max_area = max_area.max((table.x[i + 1] - table.x[i]) * table.ysup[i]);
+    }
+
+    // Equalization of the areas of the rectangles, except for zero-width
+    // rectangles which would otherwise be given an infinite height.
+    for i in 0..n {
+        let dx = table.x[i + 1] - table.x[i];
+        if dx > T::ZERO {
+            table.ysup[i] = table.ysup[i].max(max_area / dx);
+        } else {
+            table.yinf[i] = T::ZERO;
+        }
+    }
+
+    table
+}
+
+// Computes the zeros of the Hermite polynomial of degree `m` in increasing
+// order.
+//
+// The zeros are computed with Newton's method using the recurrence relation
+// of the normalized Hermite polynomials. The initial guesses are obtained by
+// inverting the asymptotic (semicircle) counting function of the zeros.
+fn hermite_zeros<T: Float>(m: usize) -> Vec<T> {
+    let mut zeros = vec![T::ZERO; m];
+    let r2 = T::cast_usize(2 * m + 1);
+    let pim4 = T::PI.powf(T::from(-0.25));
+    let tolerance = T::from(4.0) * T::EPSILON;
+
+    for i in 0..(m - m / 2) {
+        // Initial guess z = √(2m+1)·cos(φ), where φ solves
+        // φ - sin(φ)cos(φ) = 2π(i + 3/4)/(2m + 1).
+        let c = T::TWO * T::PI * (T::cast_usize(i) + T::from(0.75)) / r2;
+        let mut phi = (T::from(1.5) * c).powf(T::ONE / T::from(3.0));
+        for _ in 0..20 {
+            let sin_phi = phi.sin();
+            let delta = (phi - sin_phi * phi.cos() - c) / (T::TWO * sin_phi * sin_phi);
+            phi -= delta;
+            if delta.abs() <= tolerance * phi {
+                break;
+            }
+        }
+        let mut z = r2.sqrt() * phi.cos();
+
+        // Newton iterations. Only the ratio of the last two polynomials
+        // matters so they are rescaled as needed to prevent overflow.
+        for _ in 0..20 {
+            let mut p1 = pim4;
+            let mut p2 = T::ZERO;
+            for j in 1..=m {
+                let p3 = p2;
+                p2 = p1;
+                let j_t = T::cast_usize(j);
+                p1 = z * (T::TWO / j_t).sqrt() * p2 - ((j_t - T::ONE) / j_t).sqrt() * p3;
+                if p1.abs() > T::ONE {
+                    let s = p1.abs();
+                    p1 /= s;
+                    p2 /= s;
+                }
+            }
+            let delta = p1 / ((T::TWO * T::cast_usize(m)).sqrt() * p2);
+            z -= delta;
+            if delta.abs() <= tolerance * z.abs() {
+                break;
+            }
+        }
+
+        zeros[m - 1 - i] = z;
+        zeros[i] = -z;
+    }
+
+    zeros
+}
+
+// Recursive step of the adaptive Simpson's rule.
+//
+// The error is estimated from the difference between the Simpson's estimates
+// over the whole interval and over its two halves, which are then combined
+// with Richardson's extrapolation.
+fn adaptive_simpson<T: Float, F: UnivariateFn<T>>(
+    f: &F,
+    (x0, y0): (T, T),
+    (x_mid, y_mid): (T, T),
+    (x1, y1): (T, T),
+    area: T,
+    tolerance: T,
+    depth: u32,
+) -> Result<T, QuadratureError> {
+    let x_left = T::ONE_HALF * (x0 + x_mid);
+    let x_right = T::ONE_HALF * (x_mid + x1);
+    let y_left = f.eval(x_left);
+    let y_right = f.eval(x_right);
+    let area_left = (x_mid - x0) * (y0 + T::from(4.0) * y_left + y_mid) / T::from(6.0);
+    let area_right = (x1 - x_mid) * (y_mid + T::from(4.0) * y_right + y1) / T::from(6.0);
+    let delta = area_left + area_right - area;
+
+    if delta.abs() <= T::from(15.0) * tolerance {
+        return Ok(area_left + area_right + delta / T::from(15.0));
+    }
+    if depth == 0 {
+        return Err(QuadratureError::MaxDepthExceeded);
+    }
+
+    let half_tolerance = T::ONE_HALF * tolerance;
+    let left = adaptive_simpson(
+        f,
+        (x0, y0),
+        (x_left, y_left),
+        (x_mid, y_mid),
+        area_left,
+        half_tolerance,
+        depth - 1,
+    )?;
+    let right = adaptive_simpson(
+        f,
+        (x_mid, y_mid),
+        (x_right, y_right),
+        (x1, y1),
+        area_right,
+        half_tolerance,
+        depth - 1,
+    )?;
+
+    Ok(left + right)
+}
+
 // Tri-diagonal matrix algorithm.
 //
 // For the sake of efficiency, diagonal terms and RHS are modified in-place.
@@ -408,3 +1695,131 @@ fn solve_tma<T: Float>(a: &[T], b: &mut [T], c: &[T], rhs: &mut [T], sol: &mut [
         sol[i] = (rhs[i] - c[i] * sol[i + 1]) / b[i];
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::Rng;
+    use rand_pcg::Lcg128Xsl64;
+
+    // Solves the system with copies of the diagonal and RHS and returns the
+    // maximum absolute residual `|A * sol - rhs|`.
+    fn tma_residual(a: &[f64], b: &[f64], c: &[f64], rhs: &[f64]) -> f64 {
+        let m = a.len();
+        let mut sol = vec![0.0; m];
+        solve_tma(a, &mut b.to_vec(), c, &mut rhs.to_vec(), &mut sol);
+
+        (0..m)
+            .map(|i| {
+                let mut y = b[i] * sol[i];
+                if i > 0 {
+                    y += a[i] * sol[i - 1];
+                }
+                if i + 1 < m {
+                    y += c[i] * sol[i + 1];
+                }
+                (y - rhs[i]).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn solve_tma_small_systems() {
+        // 1×1 system.
+        let mut sol = [0.0];
+        solve_tma(&[0.0], &mut [4.0], &[0.0], &mut [2.0], &mut sol);
+        assert_eq!(sol, [0.5]);
+
+        // 2×2 system [[2, 1], [1, 3]] with solution [1, 2].
+        let mut sol = [0.0; 2];
+        solve_tma(
+            &[0.0, 1.0],
+            &mut [2.0, 3.0],
+            &[1.0, 0.0],
+            &mut [4.0, 7.0],
+            &mut sol,
+        );
+        assert!((sol[0] - 1.0).abs() < 1.0e-15);
+        assert!((sol[1] - 2.0).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn solve_tma_ill_conditioned() {
+        // Discrete Laplacian shifted such that its smallest eigenvalue is
+        // 1e-10, which gives a condition number of about 4e10. No row is
+        // diagonally dominant and the pivots decrease until the last one
+        // nearly vanishes.
+        let m = 50;
+        let delta = 1.0e-10;
+        let lambda_min = 2.0 - 2.0 * (std::f64::consts::PI / (m + 1) as f64).cos();
+        let a = vec![-1.0; m];
+        let b = vec![2.0 - lambda_min + delta; m];
+        let c = vec![-1.0; m];
+
+        // The eigenvector associated to the smallest eigenvalue.
+        let x: Vec<f64> = (0..m)
+            .map(|i| ((i + 1) as f64 * std::f64::consts::PI / (m + 1) as f64).sin())
+            .collect();
+        let rhs: Vec<f64> = x.iter().map(|&x_i| delta * x_i).collect();
+
+        let mut pivots = b.clone();
+        let mut sol = vec![0.0; m];
+        solve_tma(&a, &mut pivots, &c, &mut rhs.clone(), &mut sol);
+        assert!(pivots[m - 1].abs() < 1.0e-5);
+
+        // Without pivoting, the algorithm remains backward stable for this
+        // symmetric positive definite system: the residual is of the order of
+        // the round-off error and the error on the solution is bounded by the
+        // condition number times the machine epsilon.
+        let residual = tma_residual(&a, &b, &c, &rhs);
+        let error = (0..m).map(|i| (sol[i] - x[i]).abs()).fold(0.0, f64::max);
+        assert!(residual < f64::EPSILON * 16.0 * m as f64);
+        assert!(error < 4.0e10 * f64::EPSILON * 10.0);
+    }
+
+    #[test]
+    fn solve_tma_alternating_off_diagonals() {
+        let m = 100;
+        let a: Vec<f64> = (0..m)
+            .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+            .collect();
+        let c: Vec<f64> = (0..m)
+            .map(|i| if i % 2 == 0 { -1.0 } else { 1.0 })
+            .collect();
+        let b = vec![2.5; m];
+        let rhs: Vec<f64> = (0..m).map(|i| i as f64).collect();
+
+        assert!(tma_residual(&a, &b, &c, &rhs) < 1.0e-12 * m as f64 * m as f64);
+    }
+
+    #[test]
+    fn solve_tma_random_diagonally_dominant() {
+        let mut rng = Lcg128Xsl64::new(0xcafef00dd15ea5e5, 0xa02bdbf7bb3c0a7ac28fa16a64abf96);
+
+        for _ in 0..10_000 {
+            let m = rng.gen_range(1..50);
+            let a: Vec<f64> = (0..m).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let c: Vec<f64> = (0..m).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let b: Vec<f64> = (0..m)
+                .map(|i| {
+                    let d = a[i].abs() + c[i].abs() + rng.gen_range(1.0e-3..1.0);
+                    if rng.gen() {
+                        d
+                    } else {
+                        -d
+                    }
+                })
+                .collect();
+            let rhs: Vec<f64> = (0..m).map(|_| rng.gen_range(-1.0..1.0)).collect();
+
+            let residual = tma_residual(&a, &b, &c, &rhs);
+            assert!(
+                residual < f64::EPSILON * 16.0 * m as f64,
+                "m = {}, residual = {}",
+                m,
+                residual
+            );
+        }
+    }
+}
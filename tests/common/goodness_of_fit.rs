@@ -96,7 +96,7 @@ pub fn chi_square_test<F: Fn(f64) -> f64>(histogram: Histogram, cdf: F) -> f64 {
     // Assume that `k` is large enough to approximate the χ² distribution with a
     // normal distribution.
     let k = k as f64;
-    let p_value = (1.0 - ((chi_square - k) / (2.0 * k.sqrt())).erf()) / 2.0;
+    let p_value = (1.0 - Float::erf((chi_square - k) / (2.0 * k.sqrt()))) / 2.0;
 
     p_value
 }
@@ -115,7 +115,7 @@ pub fn goodness_of_fit<T: TestFloat, D: Distribution<T>, F: Fn(f64) -> f64>(
     // Sample the distribution.
     let mut histogram = Histogram::new(x0, x1, bin_count);
     let mut rng = test_rng();
-    
+
     for _ in 0..sample_count {
         let r = distribution.sample(&mut rng);
         histogram.add(r.as_f64());
@@ -145,7 +145,7 @@ pub fn fair_goodness_of_fit<T: TestFloat, D: Distribution<T>, F: Fn(f64) -> f64>
     // Sample the distribution.
     let mut histogram = Histogram::new(0.0, 1.0, bin_count);
     let mut rng = test_rng();
-    
+
     for _ in 0..sample_count {
         let r = distribution.sample(&mut rng);
         histogram.add(cdf(r.as_f64()));
@@ -157,3 +157,21 @@ pub fn fair_goodness_of_fit<T: TestFloat, D: Distribution<T>, F: Fn(f64) -> f64>
 
     assert!(p_value > p_value_threshold);
 }
+
+/// Returns the Kolmogorov-Smirnov statistic of a sorted sample.
+///
+/// At the 1% significance level, the critical value is approximately
+/// `1.63 / √n` for large sample counts `n`.
+#[allow(dead_code)]
+pub fn kolmogorov_smirnov<F: Fn(f64) -> f64>(sorted_samples: &[f64], cdf: F) -> f64 {
+    let n = sorted_samples.len() as f64;
+
+    sorted_samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let y = cdf(x);
+            (y - i as f64 / n).max((i + 1) as f64 / n - y)
+        })
+        .fold(0.0, f64::max)
+}
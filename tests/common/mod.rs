@@ -3,5 +3,9 @@ mod goodness_of_fit;
 mod util;
 
 pub use collisions::collisions;
-pub use goodness_of_fit::fair_goodness_of_fit;
-pub use util::TestFloat;
+#[cfg(feature = "sorted_samples")]
+pub use goodness_of_fit::kolmogorov_smirnov;
+#[cfg(feature = "rayon")]
+pub use goodness_of_fit::{chi_square_test, Histogram};
+pub use goodness_of_fit::{fair_goodness_of_fit, goodness_of_fit};
+pub use util::{test_rng, TestFloat};
@@ -4,7 +4,7 @@ use std::f64;
 
 // CDF for Cauchy distribution.
 fn cauchy_cdf(x: f64, location: f64, scale: f64) -> f64 {
-    ((x - location)/scale).atan() /f64::consts::PI + 0.5
+    ((x - location) / scale).atan() / f64::consts::PI + 0.5
 }
 
 #[test]
@@ -22,7 +22,6 @@ fn cauchy_32_collisions() {
     );
 }
 
-
 #[test]
 fn cauchy_64_collisions() {
     let location = -1.7_f64;
@@ -42,7 +41,7 @@ fn cauchy_64_collisions() {
 fn cauchy_32_fit() {
     let location = 2.2_f64;
     let scale = 3.4_f64;
-    
+
     fair_goodness_of_fit(
         Cauchy::new(location as f32, scale as f32).unwrap(),
         |x| cauchy_cdf(x, location, scale),
@@ -57,7 +56,7 @@ fn cauchy_32_fit() {
 fn cauchy_64_fit() {
     let location = 2.2_f64;
     let scale = 3.4_f64;
-    
+
     fair_goodness_of_fit(
         Cauchy::new(location as f64, scale as f64).unwrap(),
         |x| cauchy_cdf(x, location, scale),
@@ -66,3 +65,23 @@ fn cauchy_64_fit() {
         0.01,
     );
 }
+
+#[test]
+fn cauchy_64_quantile() {
+    let location = 2.2_f64;
+    let scale = 3.4_f64;
+    let dist = Cauchy::new(location, scale).unwrap();
+
+    assert!((dist.quantile(0.5) - location).abs() <= 1.0e-12);
+    for &p in &[0.001, 0.02, 0.3, 0.7, 0.98, 0.999] {
+        let cdf = cauchy_cdf(dist.quantile(p), location, scale);
+        assert!((cdf - p).abs() <= 1.0e-12);
+    }
+}
+
+#[test]
+fn cauchy_parameters() {
+    let dist = Cauchy::new(-1.5, 0.7).unwrap();
+    assert_eq!(dist.location(), -1.5);
+    assert_eq!(dist.scale(), 0.7);
+}
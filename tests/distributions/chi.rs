@@ -0,0 +1,157 @@
+use crate::common::{collisions, fair_goodness_of_fit, test_rng, TestFloat};
+use etf::distributions::{ChiDist, ChiDistDirect, ChiError, ChiFloat};
+use etf::num::Float;
+use etf::primitives::Distribution;
+use std::f64;
+
+// CDF for the half-normal distribution (χ with k=1).
+fn half_normal_cdf(x: f64) -> f64 {
+    Float::erf(x * 0.5_f64.sqrt())
+}
+
+// CDF for the Rayleigh distribution (χ with k=2).
+fn rayleigh_cdf(x: f64) -> f64 {
+    1.0 - (-0.5 * x * x).exp()
+}
+
+// CDF for the Maxwell-Boltzmann distribution (χ with k=3).
+fn maxwell_boltzmann_cdf(x: f64) -> f64 {
+    Float::erf(x * 0.5_f64.sqrt()) - (2.0 / f64::consts::PI).sqrt() * x * (-0.5 * x * x).exp()
+}
+
+fn chi_collisions<T: TestFloat + ChiFloat, F: Fn(f64) -> f64>(k: T, cdf: F) {
+    collisions(ChiDist::new(k).unwrap(), cdf, 20, 64, 10, 0.05);
+}
+
+fn chi_fit<T: TestFloat + ChiFloat, F: Fn(f64) -> f64>(k: T, cdf: F) {
+    fair_goodness_of_fit(ChiDist::new(k).unwrap(), cdf, 50_000_000, 401, 0.01);
+}
+
+fn chi_direct_collisions<T: TestFloat + ChiFloat, F: Fn(f64) -> f64>(k: T, cdf: F) {
+    collisions(ChiDistDirect::new(k).unwrap(), cdf, 20, 64, 10, 0.05);
+}
+
+fn chi_direct_fit<T: TestFloat + ChiFloat, F: Fn(f64) -> f64>(k: T, cdf: F) {
+    fair_goodness_of_fit(ChiDistDirect::new(k).unwrap(), cdf, 50_000_000, 401, 0.01);
+}
+
+#[test]
+fn chi_32_collisions_k1() {
+    chi_collisions(1_f32, half_normal_cdf);
+}
+
+#[test]
+fn chi_64_collisions_k1() {
+    chi_collisions(1_f64, half_normal_cdf);
+}
+
+#[test]
+fn chi_64_collisions_k2() {
+    chi_collisions(2_f64, rayleigh_cdf);
+}
+
+#[test]
+fn chi_64_collisions_k3() {
+    chi_collisions(3_f64, maxwell_boltzmann_cdf);
+}
+
+#[test]
+fn chi_32_fit_k1() {
+    chi_fit(1_f32, half_normal_cdf);
+}
+
+#[test]
+fn chi_64_fit_k1() {
+    chi_fit(1_f64, half_normal_cdf);
+}
+
+#[test]
+fn chi_32_fit_k2() {
+    chi_fit(2_f32, rayleigh_cdf);
+}
+
+#[test]
+fn chi_64_fit_k2() {
+    chi_fit(2_f64, rayleigh_cdf);
+}
+
+#[test]
+fn chi_64_fit_k3() {
+    chi_fit(3_f64, maxwell_boltzmann_cdf);
+}
+
+#[test]
+fn chi_direct_32_collisions_k1() {
+    chi_direct_collisions(1_f32, half_normal_cdf);
+}
+
+#[test]
+fn chi_direct_64_collisions_k1() {
+    chi_direct_collisions(1_f64, half_normal_cdf);
+}
+
+#[test]
+fn chi_direct_64_collisions_k2() {
+    chi_direct_collisions(2_f64, rayleigh_cdf);
+}
+
+#[test]
+fn chi_direct_64_collisions_k3() {
+    chi_direct_collisions(3_f64, maxwell_boltzmann_cdf);
+}
+
+#[test]
+fn chi_direct_32_fit_k2() {
+    chi_direct_fit(2_f32, rayleigh_cdf);
+}
+
+#[test]
+fn chi_direct_64_fit_k1() {
+    chi_direct_fit(1_f64, half_normal_cdf);
+}
+
+#[test]
+fn chi_direct_64_fit_k2() {
+    chi_direct_fit(2_f64, rayleigh_cdf);
+}
+
+#[test]
+fn chi_direct_64_fit_k3() {
+    chi_direct_fit(3_f64, maxwell_boltzmann_cdf);
+}
+
+#[test]
+fn chi_direct_64_second_moment_k1000() {
+    let k = 1000_f64;
+    let sample_count = 10_000_000;
+    let dist = ChiDistDirect::new(k).unwrap();
+    let mut rng = test_rng();
+
+    let sum: f64 = (0..sample_count)
+        .map(|_| {
+            let x = dist.sample(&mut rng);
+            x * x
+        })
+        .sum();
+
+    assert!((sum / sample_count as f64 - k).abs() < 1.0e-3 * k);
+}
+
+#[test]
+fn chi_direct_errors() {
+    assert!(matches!(ChiDistDirect::new(0_f64), Err(ChiError::BadDof)));
+    assert!(matches!(
+        ChiDistDirect::new(0.5_f64),
+        Err(ChiError::SingularDof)
+    ));
+}
+
+#[test]
+fn chi_parameters() {
+    for &k in &[0.3, 3.0, 17.0] {
+        assert_eq!(ChiDist::new(k).unwrap().dof(), k);
+    }
+    for &k in &[1.0, 3.0, 17.0] {
+        assert_eq!(ChiDistDirect::new(k).unwrap().dof(), k);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::common::{collisions, fair_goodness_of_fit, TestFloat};
+#[cfg(feature = "sorted_samples")]
+use crate::common::{kolmogorov_smirnov, test_rng};
 use etf::distributions::{ChiSquared, ChiSquaredFloat};
 
 #[cfg(all(feature = "rand_distribution"))]
@@ -31,21 +33,24 @@ fn chi_squared_fit<T: TestFloat + ChiSquaredFloat>(k: T) {
 }
 
 macro_rules! test_case {
-    ($ffit:ident, $fcoll:ident, $v:expr) => {
+    ($(#[$fit_attr:meta])* $ffit:ident, $(#[$coll_attr:meta])* $fcoll:ident, $v:expr) => {
         #[test]
+        $(#[$fit_attr])*
         fn $ffit() {
             chi_squared_fit($v);
         }
         #[test]
+        $(#[$coll_attr])*
         fn $fcoll() {
             chi_squared_collisions($v);
         }
     };
 }
 macro_rules! rand_test_case {
-    ($ffit:ident, $fcoll:ident, $v:expr) => {
+    ($(#[$fit_attr:meta])* $ffit:ident, $(#[$coll_attr:meta])* $fcoll:ident, $v:expr) => {
         #[cfg(feature = "rand_distribution")]
         #[test]
+        $(#[$fit_attr])*
         fn $ffit() {
             fair_goodness_of_fit(
                 rand_distr::ChiSquared::new($v).unwrap(),
@@ -57,6 +62,7 @@ macro_rules! rand_test_case {
         }
         #[cfg(feature = "rand_distribution")]
         #[test]
+        $(#[$coll_attr])*
         fn $fcoll() {
             collisions(
                 rand_distr::ChiSquared::new($v).unwrap(),
@@ -74,6 +80,9 @@ macro_rules! rand_test_case {
 // this seems to be due to floating-point precision issues that arise during
 // testing, in particular in the evaluation of the lower regularized incomplete
 // gamma function.
+//
+// The tests that are known to fail for `f32`, including with the reference
+// `rand_distr::ChiSquared` implementation, are ignored.
 
 test_case!(
     chi_squared_64_fit_k0_02,
@@ -104,6 +113,7 @@ test_case!(
 );
 test_case!(
     chi_squared_32_fit_k10000,
+    #[ignore = "too many collisions due to the resolution of f32 around 10⁴, as with rand_distr"]
     chi_squared_32_collisions_k10000,
     10_000_f32
 );
@@ -114,7 +124,9 @@ test_case!(
 ); // fails for f32
 
 rand_test_case!(
+    #[ignore = "f32 precision is insufficient for the test at small k"]
     rand_chi_squared_32_fit_k0_02,
+    #[ignore = "f32 precision is insufficient for the test at small k"]
     rand_chi_squared_32_collisions_k0_02,
     0.02_f32
 );
@@ -125,6 +137,7 @@ rand_test_case!(
 );
 rand_test_case!(
     rand_chi_squared_32_fit_k10000,
+    #[ignore = "too many collisions due to the resolution of f32 around 10⁴"]
     rand_chi_squared_32_collisions_k10000,
     10_000_f32
 );
@@ -133,3 +146,48 @@ rand_test_case!(
     rand_chi_squared_64_collisions_k10000,
     10_000_f64
 );
+
+#[test]
+fn chi_squared_64_quantile() {
+    for &k in &[0.5_f64, 2.0, 4.5, 10_000.0] {
+        let dist = ChiSquared::new(k).unwrap();
+        for &p in &[0.001, 0.02, 0.3, 0.5, 0.7, 0.98, 0.999] {
+            let cdf = chi_squared_cdf(dist.quantile(p), k);
+            assert!((cdf - p).abs() <= 1.0e-9);
+        }
+    }
+}
+
+#[cfg(feature = "sorted_samples")]
+#[test]
+fn chi_squared_64_sample_sorted_tiny_dof() {
+    // The quantiles of low order underflow for such a small number of degrees
+    // of freedom.
+    let samples = ChiSquared::new(0.002_f64)
+        .unwrap()
+        .sample_sorted(&mut test_rng(), 1_000);
+
+    assert!(samples.windows(2).all(|x| x[0] <= x[1]));
+    assert!(samples.iter().all(|&x| x >= 0.0 && x.is_finite()));
+}
+
+#[cfg(feature = "sorted_samples")]
+#[test]
+fn chi_squared_64_sample_sorted() {
+    let k = 4.5_f64;
+    let n = 100_000;
+    let samples = ChiSquared::new(k)
+        .unwrap()
+        .sample_sorted(&mut test_rng(), n);
+
+    assert!(samples.windows(2).all(|x| x[0] <= x[1]));
+    let d = kolmogorov_smirnov(&samples, |x| chi_squared_cdf(x, k));
+    assert!(d < 1.63 / (n as f64).sqrt());
+}
+
+#[test]
+fn chi_squared_parameters() {
+    for &k in &[0.3, 3.0, 17.0] {
+        assert_eq!(ChiSquared::new(k).unwrap().dof(), k);
+    }
+}
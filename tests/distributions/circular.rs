@@ -0,0 +1,118 @@
+use crate::common::{collisions, fair_goodness_of_fit, test_rng, TestFloat};
+use etf::distributions::{WrappedCauchy, WrappedNormal};
+use etf::primitives::Distribution;
+use std::f64;
+
+// CDF over (-π, π] for wrapped Cauchy distribution with zero mean.
+fn wrapped_cauchy_cdf(x: f64, concentration: f64) -> f64 {
+    let c = (1.0 + concentration) / (1.0 - concentration);
+    0.5 + (c * (0.5 * x).tan()).atan() / f64::consts::PI
+}
+
+// Returns the first trigonometric moments `E[cos X]` and `E[sin X]`.
+fn trigonometric_moments<T: TestFloat, D: Distribution<T>>(
+    dist: D,
+    sample_count: u64,
+) -> (f64, f64) {
+    let mut rng = test_rng();
+    let mut cos_sum = 0.0;
+    let mut sin_sum = 0.0;
+    for _ in 0..sample_count {
+        let x = dist.sample(&mut rng).as_f64();
+        assert!(x > -f64::consts::PI && x <= f64::consts::PI);
+        cos_sum += x.cos();
+        sin_sum += x.sin();
+    }
+
+    (cos_sum / sample_count as f64, sin_sum / sample_count as f64)
+}
+
+#[test]
+fn wrapped_cauchy_32_collisions() {
+    let concentration = 0.7_f64;
+
+    collisions(
+        WrappedCauchy::new(0.0, concentration as f32).unwrap(),
+        |x| wrapped_cauchy_cdf(x, concentration),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn wrapped_cauchy_64_collisions() {
+    let concentration = 0.7_f64;
+
+    collisions(
+        WrappedCauchy::new(0.0, concentration).unwrap(),
+        |x| wrapped_cauchy_cdf(x, concentration),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn wrapped_cauchy_64_fit() {
+    let concentration = 0.3_f64;
+
+    fair_goodness_of_fit(
+        WrappedCauchy::new(0.0, concentration).unwrap(),
+        |x| wrapped_cauchy_cdf(x, concentration),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn wrapped_cauchy_64_moments() {
+    let mean = 2.5_f64;
+    let concentration = 0.6_f64;
+    let (c, s) = trigonometric_moments(WrappedCauchy::new(mean, concentration).unwrap(), 1_000_000);
+
+    assert!((c - concentration * mean.cos()).abs() < 0.005);
+    assert!((s - concentration * mean.sin()).abs() < 0.005);
+}
+
+#[test]
+fn wrapped_normal_32_moments() {
+    let mean = -2.8_f64;
+    let std_dev = 1.2_f64;
+    let (c, s) = trigonometric_moments(
+        WrappedNormal::new(mean as f32, std_dev as f32).unwrap(),
+        1_000_000,
+    );
+    let rho = (-0.5 * std_dev * std_dev).exp();
+
+    assert!((c - rho * mean.cos()).abs() < 0.005);
+    assert!((s - rho * mean.sin()).abs() < 0.005);
+}
+
+#[test]
+fn wrapped_normal_64_moments() {
+    let mean = 1.1_f64;
+    let std_dev = 3.0_f64;
+    let (c, s) = trigonometric_moments(WrappedNormal::new(mean, std_dev).unwrap(), 1_000_000);
+    let rho = (-0.5 * std_dev * std_dev).exp();
+
+    assert!((c - rho * mean.cos()).abs() < 0.005);
+    assert!((s - rho * mean.sin()).abs() < 0.005);
+}
+
+#[test]
+fn wrapped_cauchy_parameters() {
+    let dist = WrappedCauchy::new(1.2, 0.3).unwrap();
+    assert_eq!(dist.mean(), 1.2);
+    assert_eq!(dist.concentration(), 0.3);
+}
+
+#[test]
+fn wrapped_normal_parameters() {
+    let dist = WrappedNormal::new(1.2, 0.8).unwrap();
+    assert_eq!(dist.mean(), 1.2);
+    assert_eq!(dist.std_dev(), 0.8);
+}
@@ -0,0 +1,144 @@
+// Regression tests for the sampling efficiency of the built-in distributions.
+//
+// The efficiency is measured as the average number of random words drawn per
+// sample, which is 1 for a sample accepted at the first attempt within the
+// quadrature.
+//
+// The reference values do not come from a publication. They were measured
+// with `draws_per_sample` when these tests were introduced, using the default
+// test RNG and the partition sizes of the built-in distributions: P256 for
+// `Normal` and P512 for `Gamma`. Over 10^7 samples, the standard error of the
+// measurement is below 0.1%. The tests fail if the efficiency degrades by more
+// than 10% relative to the reference. This leaves room for small changes in
+// the tables, but not for an algorithmic regression such as a missing wedge or
+// a poorly fitted tail.
+
+use crate::common::{fair_goodness_of_fit, test_rng, TestFloat};
+use etf::distributions::{Gamma, GammaFloat, Normal, NormalFloat};
+use etf::num::Float;
+use etf::primitives::Distribution;
+use rand_core::{Error, RngCore};
+
+// Random number generator counting the number of random words drawn.
+struct CountingRng<R> {
+    inner: R,
+    count: u64,
+}
+
+impl<R: RngCore> RngCore for CountingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.count += 1;
+        self.inner.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.count += 1;
+        self.inner.next_u64()
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.count += 1;
+        self.inner.fill_bytes(dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.count += 1;
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+// Average number of random words drawn per sample.
+fn draws_per_sample<T: TestFloat, D: Distribution<T>>(dist: D) -> f64 {
+    let sample_count = 10_000_000;
+    let mut rng = CountingRng {
+        inner: test_rng(),
+        count: 0,
+    };
+    for _ in 0..sample_count {
+        dist.sample(&mut rng);
+    }
+
+    rng.count as f64 / sample_count as f64
+}
+
+// Checks that the efficiency did not degrade by more than 10% relative to
+// the reference number of draws per sample.
+fn assert_efficiency<T: TestFloat, D: Distribution<T>>(dist: D, reference: f64) {
+    let draws = draws_per_sample(dist);
+
+    assert!(
+        draws <= 1.1 * reference,
+        "{} draws per sample, reference: {}",
+        draws,
+        reference
+    );
+}
+
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + Float::erf(x * 0.5_f64.sqrt()))
+}
+
+fn exponential_cdf(x: f64) -> f64 {
+    1.0 - (-x).exp()
+}
+
+fn gamma_k3_cdf(x: f64) -> f64 {
+    1.0 - (-x).exp() * (1.0 + x + 0.5 * x * x)
+}
+
+fn standard_normal<T: NormalFloat>() -> Normal<T> {
+    Normal::new(T::ZERO, T::ONE).unwrap()
+}
+
+fn standard_exponential<T: GammaFloat>() -> Gamma<T> {
+    Gamma::new(T::ONE, T::ONE).unwrap()
+}
+
+fn gamma_k3<T: GammaFloat>() -> Gamma<T> {
+    Gamma::new(T::from(3.0), T::ONE).unwrap()
+}
+
+#[test]
+fn standard_normal_32() {
+    fair_goodness_of_fit(standard_normal::<f32>(), normal_cdf, 10_000_000, 401, 0.01);
+    assert_efficiency(standard_normal::<f32>(), 1.031);
+}
+
+#[test]
+fn standard_normal_64() {
+    fair_goodness_of_fit(standard_normal::<f64>(), normal_cdf, 10_000_000, 401, 0.01);
+    assert_efficiency(standard_normal::<f64>(), 1.030);
+}
+
+#[test]
+fn standard_exponential_32() {
+    fair_goodness_of_fit(
+        standard_exponential::<f32>(),
+        exponential_cdf,
+        10_000_000,
+        401,
+        0.01,
+    );
+    assert_efficiency(standard_exponential::<f32>(), 1.049);
+}
+
+#[test]
+fn standard_exponential_64() {
+    fair_goodness_of_fit(
+        standard_exponential::<f64>(),
+        exponential_cdf,
+        10_000_000,
+        401,
+        0.01,
+    );
+    assert_efficiency(standard_exponential::<f64>(), 1.023);
+}
+
+#[test]
+fn gamma_k3_32() {
+    fair_goodness_of_fit(gamma_k3::<f32>(), gamma_k3_cdf, 10_000_000, 401, 0.01);
+    assert_efficiency(gamma_k3::<f32>(), 1.048);
+}
+
+#[test]
+fn gamma_k3_64() {
+    fair_goodness_of_fit(gamma_k3::<f64>(), gamma_k3_cdf, 10_000_000, 401, 0.01);
+    assert_efficiency(gamma_k3::<f64>(), 1.029);
+}
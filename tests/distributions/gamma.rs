@@ -0,0 +1,150 @@
+use crate::common::{collisions, fair_goodness_of_fit, TestFloat};
+use etf::distributions::{Gamma, GammaFloat, GammaPrecision, LogGamma};
+
+fn gamma_cdf(x: f64, shape: f64, scale: f64) -> f64 {
+    use special::Gamma;
+    (x / scale).inc_gamma(shape)
+}
+
+fn gamma_fit<T: TestFloat + GammaFloat>(shape: T, scale: T, precision: GammaPrecision) {
+    fair_goodness_of_fit(
+        Gamma::with_precision(shape, scale, precision).unwrap(),
+        |x| gamma_cdf(x, shape.into(), scale.into()),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+fn log_gamma_fit<T: TestFloat + GammaFloat>(shape: T, scale: T) {
+    fair_goodness_of_fit(
+        LogGamma::new(shape, scale).unwrap(),
+        |y| gamma_cdf(y.exp(), shape.into(), scale.into()),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+fn gamma_collisions<T: TestFloat + GammaFloat>(shape: T, scale: T, precision: GammaPrecision) {
+    collisions(
+        Gamma::with_precision(shape, scale, precision).unwrap(),
+        |x| gamma_cdf(x, shape.into(), scale.into()),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn gamma_32_precision_construction() {
+    for &precision in &[
+        GammaPrecision::Fast,
+        GammaPrecision::Balanced,
+        GammaPrecision::High,
+    ] {
+        for &shape in &[0.02_f32, 0.5, 1.0, 2.5, 30.0, 10_000.0] {
+            assert!(Gamma::with_precision(shape, 1.5, precision).is_ok());
+        }
+    }
+}
+
+#[test]
+fn gamma_64_precision_construction() {
+    for &precision in &[
+        GammaPrecision::Fast,
+        GammaPrecision::Balanced,
+        GammaPrecision::High,
+    ] {
+        for &shape in &[0.02_f64, 0.5, 1.0, 2.5, 30.0, 10_000.0, 1.0e6] {
+            assert!(Gamma::with_precision(shape, 1.5, precision).is_ok());
+        }
+    }
+}
+
+#[test]
+fn gamma_32_fit_high_precision() {
+    gamma_fit(2.5_f32, 1.5, GammaPrecision::High);
+}
+
+#[test]
+fn gamma_32_collisions_high_precision() {
+    gamma_collisions(2.5_f32, 1.5, GammaPrecision::High);
+}
+
+#[test]
+fn gamma_64_fit_fast() {
+    gamma_fit(0.5_f64, 1.5, GammaPrecision::Fast);
+}
+
+#[test]
+fn gamma_64_collisions_fast() {
+    gamma_collisions(0.5_f64, 1.5, GammaPrecision::Fast);
+}
+
+#[test]
+fn gamma_32_fit_k1_001() {
+    gamma_fit(1.001_f32, 1.0, GammaPrecision::Balanced);
+}
+
+#[test]
+fn gamma_64_fit_k1_001() {
+    gamma_fit(1.001_f64, 1.0, GammaPrecision::High);
+}
+
+#[test]
+fn gamma_64_collisions_k0_999() {
+    gamma_collisions(0.999_f64, 1.0, GammaPrecision::High);
+}
+
+#[test]
+fn gamma_parameters() {
+    for &(shape, scale) in &[(0.3, 2.0), (1.0, 0.5), (7.5, 3.0)] {
+        let dist = Gamma::new(shape, scale).unwrap();
+        assert_eq!(dist.shape(), shape);
+        assert_eq!(dist.scale(), scale);
+    }
+}
+
+#[test]
+fn log_gamma_32_fit_k0_3() {
+    log_gamma_fit(0.3_f32, 1.5);
+}
+
+#[test]
+fn log_gamma_64_fit_k0_3() {
+    log_gamma_fit(0.3_f64, 1.5);
+}
+
+#[test]
+fn log_gamma_64_fit_k0_02() {
+    log_gamma_fit(0.02_f64, 2.0);
+}
+
+#[test]
+fn log_gamma_64_fit_k3() {
+    log_gamma_fit(3.0_f64, 1.5);
+}
+
+#[test]
+fn log_gamma_parameters() {
+    let dist = LogGamma::new(0.3, 2.0).unwrap();
+    assert_eq!(dist.shape(), 0.3);
+    assert_eq!(dist.scale(), 2.0);
+}
+
+#[test]
+fn gamma_64_quantile_tiny_shape() {
+    // For very small shapes, `P(k, x) ≈ x^k / Γ(k + 1)` and the quantiles of
+    // low order underflow.
+    let shape = 0.001_f64;
+    let dist = Gamma::new(shape, 1.0).unwrap();
+
+    let q = dist.quantile(0.001);
+    assert!((0.0..1.0e-300).contains(&q));
+
+    let expected = (0.5 * special::Gamma::gamma(shape + 1.0)).powf(1.0 / shape);
+    let q = dist.quantile(0.5);
+    assert!((q - expected).abs() <= 1.0e-9 * expected);
+}
@@ -1,5 +1,6 @@
-use crate::common::{collisions, fair_goodness_of_fit};
+use crate::common::{collisions, fair_goodness_of_fit, test_rng};
 use etf::distributions::Gumbel;
+use etf::primitives::Distribution;
 use std::f64;
 
 // CDF for Gumbel distribution.
@@ -66,3 +67,36 @@ fn gumbel_64_fit() {
         0.01,
     );
 }
+
+#[test]
+fn gumbel_64_quantile() {
+    let location = 2.2_f64;
+    let scale = 3.4_f64;
+    let dist = Gumbel::new(location, scale).unwrap();
+
+    for &p in &[0.001, 0.02, 0.3, 0.5, 0.7, 0.98, 0.999] {
+        let cdf = gumbel_cdf(dist.quantile(p), location, scale);
+        assert!((cdf - p).abs() <= 1.0e-12);
+    }
+}
+
+#[test]
+fn gumbel_parameters() {
+    let dist = Gumbel::new(-1.5, 0.7).unwrap();
+    assert_eq!(dist.location(), -1.5);
+    assert_eq!(dist.scale(), 0.7);
+    assert!((dist.mean() - (-1.5 + 0.7 * 0.5772156649015329_f64)).abs() < 1.0e-15);
+}
+
+#[test]
+fn gumbel_64_mean() {
+    let dist = Gumbel::new(-1.5, 0.7).unwrap();
+    let mut rng = test_rng();
+
+    let n = 1_000_000;
+    let sample_mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+
+    // The standard deviation of the Gumbel distribution is `π β / √6`.
+    let std_dev = f64::consts::PI * 0.7 / 6.0_f64.sqrt();
+    assert!((sample_mean - dist.mean()).abs() < 5.0 * std_dev / (n as f64).sqrt());
+}
@@ -0,0 +1,85 @@
+use crate::common::{collisions, fair_goodness_of_fit};
+use etf::distributions::HalfCauchy;
+use std::f64;
+
+// CDF for half-Cauchy distribution.
+fn half_cauchy_cdf(x: f64, scale: f64) -> f64 {
+    if x < 0.0 {
+        return 0.0;
+    }
+
+    2.0 * (x / scale).atan() / f64::consts::PI
+}
+
+#[test]
+fn half_cauchy_32_collisions() {
+    let scale = 2.8_f64;
+
+    collisions(
+        HalfCauchy::new(scale as f32).unwrap(),
+        |x| half_cauchy_cdf(x, scale),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn half_cauchy_64_collisions() {
+    let scale = 2.8_f64;
+
+    collisions(
+        HalfCauchy::new(scale).unwrap(),
+        |x| half_cauchy_cdf(x, scale),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn half_cauchy_32_fit() {
+    let scale = 3.4_f64;
+
+    fair_goodness_of_fit(
+        HalfCauchy::new(scale as f32).unwrap(),
+        |x| half_cauchy_cdf(x, scale),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn half_cauchy_64_fit() {
+    let scale = 3.4_f64;
+
+    fair_goodness_of_fit(
+        HalfCauchy::new(scale).unwrap(),
+        |x| half_cauchy_cdf(x, scale),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn half_cauchy_64_quantile() {
+    let scale = 3.4_f64;
+    let dist = HalfCauchy::new(scale).unwrap();
+
+    assert_eq!(dist.quantile(0.0), 0.0);
+    for &p in &[0.001, 0.02, 0.3, 0.7, 0.98, 0.999] {
+        let cdf = half_cauchy_cdf(dist.quantile(p), scale);
+        assert!((cdf - p).abs() <= 1.0e-12);
+    }
+}
+
+#[test]
+fn half_cauchy_parameters() {
+    assert_eq!(HalfCauchy::new(0.7).unwrap().scale(), 0.7);
+    assert!(HalfCauchy::new(0.0).is_err());
+    assert!(HalfCauchy::new(-1.0f32).is_err());
+}
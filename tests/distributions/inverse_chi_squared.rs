@@ -0,0 +1,91 @@
+use crate::common::{collisions, fair_goodness_of_fit, TestFloat};
+use etf::distributions::{ChiSquaredFloat, InverseChi2, ScaledInverseChi2};
+
+// CDF for the scaled inverse χ² distribution, which reduces to the inverse χ²
+// distribution for `ν τ² = 1`.
+fn scaled_inverse_chi_squared_cdf(x: f64, nu: f64, tau_sq: f64) -> f64 {
+    use special::Gamma;
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    1.0 - (0.5 * nu * tau_sq / x).inc_gamma(0.5 * nu)
+}
+
+fn inverse_chi_squared_collisions<T: TestFloat + ChiSquaredFloat>(nu: T) {
+    collisions(
+        InverseChi2::new(nu).unwrap(),
+        |x| scaled_inverse_chi_squared_cdf(x, nu.into(), 1.0 / nu.into()),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+fn inverse_chi_squared_fit<T: TestFloat + ChiSquaredFloat>(nu: T) {
+    fair_goodness_of_fit(
+        InverseChi2::new(nu).unwrap(),
+        |x| scaled_inverse_chi_squared_cdf(x, nu.into(), 1.0 / nu.into()),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+fn scaled_inverse_chi_squared_fit<T: TestFloat + ChiSquaredFloat>(nu: T, tau_sq: T) {
+    fair_goodness_of_fit(
+        ScaledInverseChi2::new(nu, tau_sq).unwrap(),
+        |x| scaled_inverse_chi_squared_cdf(x, nu.into(), tau_sq.into()),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn inverse_chi_squared_32_fit_nu1() {
+    inverse_chi_squared_fit(1.0f32);
+}
+
+#[test]
+fn inverse_chi_squared_64_fit_nu4() {
+    inverse_chi_squared_fit(4.0f64);
+}
+
+#[test]
+fn inverse_chi_squared_64_fit_nu10() {
+    inverse_chi_squared_fit(10.0f64);
+}
+
+#[test]
+fn inverse_chi_squared_64_collisions_nu4() {
+    inverse_chi_squared_collisions(4.0f64);
+}
+
+#[test]
+fn scaled_inverse_chi_squared_32_fit_nu1() {
+    scaled_inverse_chi_squared_fit(1.0f32, 2.5f32);
+}
+
+#[test]
+fn scaled_inverse_chi_squared_64_fit_nu4() {
+    scaled_inverse_chi_squared_fit(4.0f64, 0.3f64);
+}
+
+#[test]
+fn scaled_inverse_chi_squared_64_fit_nu10() {
+    scaled_inverse_chi_squared_fit(10.0f64, 7.0f64);
+}
+
+#[test]
+fn inverse_chi_squared_parameters() {
+    assert_eq!(InverseChi2::new(4.0).unwrap().dof(), 4.0);
+    assert!(InverseChi2::new(0.0).is_err());
+
+    let dist = ScaledInverseChi2::new(10.0, 0.25).unwrap();
+    assert_eq!(dist.dof(), 10.0);
+    assert_eq!(dist.scale(), 0.25);
+    assert!(ScaledInverseChi2::new(-1.0, 0.25).is_err());
+    assert!(ScaledInverseChi2::new(1.0, 0.0).is_err());
+}
@@ -0,0 +1,133 @@
+use crate::common::{collisions, fair_goodness_of_fit};
+use etf::distributions::{Kumaraswamy, KumaraswamyETF};
+
+// CDF for the Kumaraswamy distribution.
+fn kumaraswamy_cdf(x: f64, a: f64, b: f64) -> f64 {
+    1.0 - (1.0 - x.powf(a)).powf(b)
+}
+
+#[test]
+fn kumaraswamy_32_collisions() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    collisions(
+        Kumaraswamy::new(a as f32, b as f32).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn kumaraswamy_64_collisions() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    collisions(
+        Kumaraswamy::new(a, b).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn kumaraswamy_32_fit() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    fair_goodness_of_fit(
+        Kumaraswamy::new(a as f32, b as f32).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn kumaraswamy_64_fit() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    fair_goodness_of_fit(
+        Kumaraswamy::new(a, b).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn kumaraswamy_etf_32_collisions() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    collisions(
+        KumaraswamyETF::new(a as f32, b as f32).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn kumaraswamy_etf_64_collisions() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    collisions(
+        KumaraswamyETF::new(a, b).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn kumaraswamy_etf_32_fit() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    fair_goodness_of_fit(
+        KumaraswamyETF::new(a as f32, b as f32).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn kumaraswamy_etf_64_fit() {
+    let (a, b) = (2.0_f64, 5.0_f64);
+
+    fair_goodness_of_fit(
+        KumaraswamyETF::new(a, b).unwrap(),
+        |x| kumaraswamy_cdf(x, a, b),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn kumaraswamy_bad_shape() {
+    assert!(Kumaraswamy::new(0.0_f64, 1.0).is_err());
+    assert!(Kumaraswamy::new(1.0_f64, -1.0).is_err());
+    assert!(KumaraswamyETF::new(0.5_f64, 2.0).is_err());
+}
+
+#[test]
+fn kumaraswamy_parameters() {
+    let dist = Kumaraswamy::new(0.3, 2.5).unwrap();
+    assert_eq!(dist.a(), 0.3);
+    assert_eq!(dist.b(), 2.5);
+
+    let dist = KumaraswamyETF::new(1.5, 2.5).unwrap();
+    assert_eq!(dist.a(), 1.5);
+    assert_eq!(dist.b(), 2.5);
+}
@@ -0,0 +1,145 @@
+use crate::common::{collisions, fair_goodness_of_fit};
+use etf::distributions::{LogOddsBeta, LogRatioNormal};
+use etf::num::Float;
+use std::f64;
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+// CDF for the logit-normal distribution.
+fn logit_normal_cdf(p: f64, mean: f64, std_dev: f64) -> f64 {
+    if p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+    let z = ((p / (1.0 - p)).ln() - mean) / std_dev;
+
+    0.5 * (1.0 + Float::erf(z * f64::consts::FRAC_1_SQRT_2))
+}
+
+#[test]
+fn log_ratio_normal_32_fit() {
+    // The sample count is limited by the accuracy of the underlying `f32`
+    // normal sampler rather than by the logistic transform.
+    fair_goodness_of_fit(
+        LogRatioNormal::new(0.5f32, 1.5f32).unwrap(),
+        |p| logit_normal_cdf(p, 0.5, 1.5),
+        5_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_ratio_normal_64_fit() {
+    fair_goodness_of_fit(
+        LogRatioNormal::new(-1.2, 0.7).unwrap(),
+        |p| logit_normal_cdf(p, -1.2, 0.7),
+        20_000_000,
+        401,
+        0.01,
+    );
+}
+
+// CDFs of the log-odds of Beta variables for shape parameters with a
+// closed-form Beta CDF.
+fn log_odds_beta_1_1_cdf(x: f64) -> f64 {
+    sigmoid(x)
+}
+
+fn log_odds_beta_2_1_cdf(x: f64) -> f64 {
+    sigmoid(x).powi(2)
+}
+
+fn log_odds_beta_1_3_cdf(x: f64) -> f64 {
+    1.0 - sigmoid(-x).powi(3)
+}
+
+fn log_odds_beta_half_half_cdf(x: f64) -> f64 {
+    2.0 / f64::consts::PI * sigmoid(x).sqrt().asin()
+}
+
+#[test]
+fn log_odds_beta_32_fit_1_1() {
+    fair_goodness_of_fit(
+        LogOddsBeta::new(1.0f32, 1.0f32).unwrap(),
+        log_odds_beta_1_1_cdf,
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_odds_beta_64_fit_2_1() {
+    fair_goodness_of_fit(
+        LogOddsBeta::new(2.0, 1.0).unwrap(),
+        log_odds_beta_2_1_cdf,
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_odds_beta_64_fit_1_3() {
+    fair_goodness_of_fit(
+        LogOddsBeta::new(1.0, 3.0).unwrap(),
+        log_odds_beta_1_3_cdf,
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_odds_beta_32_fit_half_half() {
+    fair_goodness_of_fit(
+        LogOddsBeta::new(0.5f32, 0.5f32).unwrap(),
+        log_odds_beta_half_half_cdf,
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_odds_beta_64_fit_half_half() {
+    fair_goodness_of_fit(
+        LogOddsBeta::new(0.5, 0.5).unwrap(),
+        log_odds_beta_half_half_cdf,
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_odds_beta_64_collisions_2_1() {
+    collisions(
+        LogOddsBeta::new(2.0, 1.0).unwrap(),
+        log_odds_beta_2_1_cdf,
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn log_ratio_parameters() {
+    let dist = LogRatioNormal::new(0.3, 2.0).unwrap();
+    assert_eq!(dist.mean(), 0.3);
+    assert_eq!(dist.std_dev(), 2.0);
+    assert!(LogRatioNormal::new(0.0, 0.0).is_err());
+
+    let dist = LogOddsBeta::new(0.5, 4.0).unwrap();
+    assert_eq!(dist.alpha(), 0.5);
+    assert_eq!(dist.beta(), 4.0);
+    assert!(LogOddsBeta::new(0.0, 1.0).is_err());
+    assert!(LogOddsBeta::new(1.0, -1.0).is_err());
+    assert!(LogOddsBeta::new(1.0e3, 2.0e3).is_ok());
+}
@@ -0,0 +1,88 @@
+use crate::common::{collisions, fair_goodness_of_fit, test_rng};
+use etf::distributions::MaxwellBoltzmann;
+use etf::num::Float;
+use etf::primitives::Distribution;
+use std::f64;
+
+// CDF for Maxwell-Boltzmann distribution.
+fn maxwell_boltzmann_cdf(x: f64, scale: f64) -> f64 {
+    let z = x / scale;
+    Float::erf(z * 0.5_f64.sqrt()) - (2.0 / f64::consts::PI).sqrt() * z * (-0.5 * z * z).exp()
+}
+
+#[test]
+fn maxwell_boltzmann_32_collisions() {
+    let scale = 2.8_f64;
+
+    collisions(
+        MaxwellBoltzmann::new(scale as f32).unwrap(),
+        |x| maxwell_boltzmann_cdf(x, scale),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn maxwell_boltzmann_64_collisions() {
+    let scale = 2.8_f64;
+
+    collisions(
+        MaxwellBoltzmann::new(scale).unwrap(),
+        |x| maxwell_boltzmann_cdf(x, scale),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn maxwell_boltzmann_32_fit() {
+    let scale = 0.4_f64;
+
+    fair_goodness_of_fit(
+        MaxwellBoltzmann::new(scale as f32).unwrap(),
+        |x| maxwell_boltzmann_cdf(x, scale),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn maxwell_boltzmann_64_fit() {
+    let scale = 0.4_f64;
+
+    fair_goodness_of_fit(
+        MaxwellBoltzmann::new(scale).unwrap(),
+        |x| maxwell_boltzmann_cdf(x, scale),
+        50_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn maxwell_boltzmann_64_second_moment() {
+    let scale = 1.7_f64;
+    let sample_count = 10_000_000;
+    let dist = MaxwellBoltzmann::new(scale).unwrap();
+    let mut rng = test_rng();
+
+    let sum: f64 = (0..sample_count)
+        .map(|_| {
+            let v = dist.sample(&mut rng);
+            v * v
+        })
+        .sum();
+    let expected = 3.0 * scale * scale;
+
+    assert!((sum / sample_count as f64 - expected).abs() < 1.0e-3 * expected);
+}
+
+#[test]
+fn maxwell_boltzmann_parameters() {
+    assert_eq!(MaxwellBoltzmann::new(1.7).unwrap().scale(), 1.7);
+}
@@ -1,4 +1,14 @@
 mod cauchy;
+mod chi;
 mod chi_squared;
+mod circular;
+mod efficiency;
+mod gamma;
 mod gumbel;
+mod half_cauchy;
+mod inverse_chi_squared;
+mod kumaraswamy;
+mod log_ratio;
+mod maxwell_boltzmann;
 mod normal;
+mod stable;
@@ -1,10 +1,12 @@
 use crate::common::{collisions, fair_goodness_of_fit};
-use etf::distributions::{Normal, CentralNormal};
+#[cfg(feature = "sorted_samples")]
+use crate::common::{kolmogorov_smirnov, test_rng};
+use etf::distributions::{CentralNormal, Normal};
 use etf::num::Float;
 
 // CDF for normal distribution.
 pub fn normal_cdf(x: f64, mean: f64, std_dev: f64) -> f64 {
-    0.5 * (1.0 + ((0.5_f64).sqrt() * (x - mean) / std_dev).erf())
+    0.5 * (1.0 + Float::erf((0.5_f64).sqrt() * (x - mean) / std_dev))
 }
 
 #[test]
@@ -22,7 +24,6 @@ fn normal_32_collisions() {
     );
 }
 
-
 #[test]
 fn normal_64_collisions() {
     let mean = -1.7_f64;
@@ -70,7 +71,7 @@ fn central_normal_64_collisions() {
 fn normal_32_fit() {
     let mean = 2.2_f64;
     let std_dev = 3.4_f64;
-    
+
     fair_goodness_of_fit(
         Normal::new(mean as f32, std_dev as f32).unwrap(),
         |x| normal_cdf(x, mean, std_dev),
@@ -84,7 +85,7 @@ fn normal_32_fit() {
 fn normal_64_fit() {
     let mean = 2.2_f64;
     let std_dev = 3.4_f64;
-    
+
     fair_goodness_of_fit(
         Normal::new(mean as f64, std_dev as f64).unwrap(),
         |x| normal_cdf(x, mean, std_dev),
@@ -97,7 +98,7 @@ fn normal_64_fit() {
 #[test]
 fn central_normal_32_fit() {
     let std_dev = 1.3_f64;
-    
+
     fair_goodness_of_fit(
         CentralNormal::new(std_dev as f32).unwrap(),
         |x| normal_cdf(x, 0.0, std_dev),
@@ -110,7 +111,7 @@ fn central_normal_32_fit() {
 #[test]
 fn central_normal_64_fit() {
     let std_dev = 1.3_f64;
-    
+
     fair_goodness_of_fit(
         CentralNormal::new(std_dev as f64).unwrap(),
         |x| normal_cdf(x, 0.0, std_dev),
@@ -119,3 +120,109 @@ fn central_normal_64_fit() {
         0.01,
     );
 }
+
+#[test]
+fn normal_64_quantile() {
+    let mean = 2.2_f64;
+    let std_dev = 3.4_f64;
+    let dist = Normal::new(mean, std_dev).unwrap();
+
+    assert!((dist.quantile(0.5) - mean).abs() <= 1.0e-12);
+    for &p in &[1.0e-10, 0.001, 0.02, 0.3, 0.7, 0.98, 0.999] {
+        let cdf = normal_cdf(dist.quantile(p), mean, std_dev);
+        assert!((cdf - p).abs() <= 1.0e-12 * p.max(1.0 - p));
+    }
+}
+
+#[test]
+fn central_normal_32_quantile() {
+    let std_dev = 1.3_f64;
+    let dist = CentralNormal::new(std_dev as f32).unwrap();
+
+    assert_eq!(dist.quantile(0.5), 0.0);
+    for &p in &[0.001_f32, 0.02, 0.3, 0.7, 0.98, 0.999] {
+        let cdf = normal_cdf(dist.quantile(p) as f64, 0.0, std_dev);
+        assert!((cdf - p as f64).abs() <= 1.0e-5);
+    }
+}
+
+#[test]
+fn normal_32_quantile_tails() {
+    let mean = 2.2_f64;
+    let std_dev = 3.4_f64;
+    let dist = Normal::new(mean as f32, std_dev as f32).unwrap();
+
+    // Lower tail, computed with the complementary error function to avoid
+    // cancellation in the reference CDF.
+    let p = 1.0e-10_f32;
+    let x = dist.quantile(p) as f64;
+    assert!(x.is_finite());
+    let cdf = 0.5 * Float::erfc((0.5_f64).sqrt() * (mean - x) / std_dev);
+    assert!((cdf - p as f64).abs() <= 1.0e-4 * p as f64);
+
+    // Upper tail.
+    let p = 1.0_f32 - 1.0e-7;
+    let x = dist.quantile(p) as f64;
+    assert!(x.is_finite());
+    let ccdf = 0.5 * Float::erfc((0.5_f64).sqrt() * (x - mean) / std_dev);
+    assert!((ccdf - (1.0 - p as f64)).abs() <= 1.0e-4 * (1.0 - p as f64));
+}
+
+#[test]
+fn central_normal_32_quantile_tails() {
+    let std_dev = 1.3_f64;
+    let dist = CentralNormal::new(std_dev as f32).unwrap();
+
+    for &p in &[1.0e-10_f32, 1.0 - 1.0e-7] {
+        let x = dist.quantile(p) as f64;
+        assert!(x.is_finite());
+        // Probability of the tail beyond `x`.
+        let tail = 0.5 * Float::erfc((0.5_f64).sqrt() * x.abs() / std_dev);
+        let expected = (p as f64).min(1.0 - p as f64);
+        assert!((tail - expected).abs() <= 1.0e-4 * expected);
+    }
+}
+
+#[cfg(feature = "sorted_samples")]
+#[test]
+fn normal_64_sample_sorted() {
+    let mean = 2.2_f64;
+    let std_dev = 3.4_f64;
+    let n = 1_000_000;
+    let samples = Normal::new(mean, std_dev)
+        .unwrap()
+        .sample_sorted(&mut test_rng(), n);
+
+    assert!(samples.windows(2).all(|x| x[0] <= x[1]));
+    let d = kolmogorov_smirnov(&samples, |x| normal_cdf(x, mean, std_dev));
+    assert!(d < 1.63 / (n as f64).sqrt());
+}
+
+#[cfg(feature = "debug_utils")]
+#[test]
+fn normal_64_deterministic_table() {
+    let table1 = Normal::new(2.2_f64, 3.4_f64).unwrap().init_table();
+    let table2 = Normal::new(2.2_f64, 3.4_f64).unwrap().init_table();
+
+    for ((x1, yinf1, ysup1), (x2, yinf2, ysup2)) in table1.iter().zip(table2.iter()) {
+        assert_eq!(x1.to_bits(), x2.to_bits());
+        assert_eq!(yinf1.to_bits(), yinf2.to_bits());
+        assert_eq!(ysup1.to_bits(), ysup2.to_bits());
+    }
+
+    // The table covers the right half of the unscaled density.
+    assert_eq!(table1.x[0], 2.2);
+    assert!(table1.ysup[0] >= 1.0 && table1.ysup[0] < 1.0 + 1.0e-6);
+    assert!(table1
+        .iter()
+        .all(|(_, yinf, ysup)| 0.0 < yinf && yinf < ysup));
+}
+
+#[test]
+fn normal_parameters() {
+    let dist = Normal::new(-1.5, 0.7).unwrap();
+    assert_eq!(dist.mean(), -1.5);
+    assert_eq!(dist.std_dev(), 0.7);
+
+    assert_eq!(CentralNormal::new(0.7).unwrap().std_dev(), 0.7);
+}
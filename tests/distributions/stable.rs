@@ -0,0 +1,144 @@
+use crate::common::{collisions, goodness_of_fit, TestFloat};
+use etf::distributions::{StableFloat, SymmetricStable};
+use etf::primitives::util::{pdf_area, pdf_area_adaptive};
+use std::f64::consts::PI;
+
+// CDF of the symmetric α-stable distribution for `α≠1`, computed from
+// Zolotarev's integral representation.
+fn stable_cdf(x: f64, alpha: f64, scale: f64) -> f64 {
+    let z = (x / scale).abs();
+    if z == 0.0 {
+        return 0.5;
+    }
+    let exponent = alpha / (alpha - 1.0);
+    let g = |theta: f64| {
+        z.powf(exponent)
+            * (theta.cos() / (alpha * theta).sin()).powf(exponent)
+            * ((alpha - 1.0) * theta).cos()
+            / theta.cos()
+    };
+    let exp_minus_g = |theta: f64| {
+        let g = g(theta);
+        if g.is_nan() {
+            0.0
+        } else {
+            (-g).exp()
+        }
+    };
+
+    // Split the integral where `exp(-g)` drops, `g` being monotonic.
+    let (mut theta_min, mut theta_max) = (0.0, 0.5 * PI);
+    for _ in 0..60 {
+        let theta = 0.5 * (theta_min + theta_max);
+        if (g(theta) < 1.0) == (alpha < 1.0) {
+            theta_min = theta;
+        } else {
+            theta_max = theta;
+        }
+    }
+    let integral = |theta0: f64, theta1: f64| {
+        pdf_area_adaptive(&exp_minus_g, theta0, theta1, 1.0e-12)
+            .unwrap_or_else(|_| pdf_area(&exp_minus_g, theta0, theta1, 100_000))
+    };
+    let integral = integral(0.0, theta_min) + integral(theta_min, 0.5 * PI);
+
+    let upper = if alpha < 1.0 {
+        0.5 + integral / PI
+    } else {
+        1.0 - integral / PI
+    };
+
+    if x > 0.0 {
+        upper
+    } else {
+        1.0 - upper
+    }
+}
+
+fn stable_fit<T: TestFloat + StableFloat>(alpha: T, scale: T, x_max: f64) {
+    let alpha_f64: f64 = alpha.into();
+    let scale_f64: f64 = scale.into();
+
+    goodness_of_fit(
+        SymmetricStable::new(alpha, scale).unwrap(),
+        |x| stable_cdf(x, alpha_f64, scale_f64),
+        -x_max,
+        x_max,
+        10_000_000,
+        401,
+        0.01,
+    );
+}
+
+fn stable_collisions<T: TestFloat + StableFloat>(alpha: T, scale: T) {
+    let alpha_f64: f64 = alpha.into();
+    let scale_f64: f64 = scale.into();
+
+    collisions(
+        SymmetricStable::new(alpha, scale).unwrap(),
+        |x| stable_cdf(x, alpha_f64, scale_f64),
+        20,
+        64,
+        10,
+        0.05,
+    );
+}
+
+#[test]
+fn stable_32_fit_alpha0_5() {
+    stable_fit(0.5_f32, 1.5, 30.0);
+}
+
+#[test]
+fn stable_64_fit_alpha0_5() {
+    stable_fit(0.5_f64, 1.5, 30.0);
+}
+
+#[test]
+fn stable_32_fit_alpha1_5() {
+    stable_fit(1.5_f32, 0.7, 10.0);
+}
+
+#[test]
+fn stable_64_fit_alpha1_5() {
+    stable_fit(1.5_f64, 0.7, 10.0);
+}
+
+// Close to `α=2`, the bulk of the distribution extends beyond the tail
+// position derived from the asymptotic Pareto prefactor.
+#[test]
+fn stable_64_fit_alpha1_999() {
+    stable_fit(1.999_f64, 1.3, 10.0);
+}
+
+#[test]
+fn stable_64_collisions_alpha0_5() {
+    stable_collisions(0.5_f64, 1.5);
+}
+
+#[test]
+fn stable_64_collisions_alpha1_5() {
+    stable_collisions(1.5_f64, 0.7);
+}
+
+#[test]
+fn stable_construction() {
+    for &alpha in &[0.3_f64, 0.8, 0.95, 1.0, 1.05, 1.2, 1.9, 1.9999, 2.0] {
+        assert!(
+            SymmetricStable::new(alpha, 2.0).is_ok(),
+            "alpha = {}",
+            alpha
+        );
+    }
+    assert!(SymmetricStable::new(0.0_f64, 1.0).is_err());
+    assert!(SymmetricStable::new(0.2_f64, 1.0).is_err());
+    assert!(SymmetricStable::new(2.1_f64, 1.0).is_err());
+    assert!(SymmetricStable::new(1.5_f64, 0.0).is_err());
+}
+
+#[test]
+fn stable_parameters() {
+    let dist = SymmetricStable::new(1.5, 0.7).unwrap();
+    assert_eq!(dist.alpha(), 1.5);
+    assert_eq!(dist.scale(), 0.7);
+}
@@ -0,0 +1,77 @@
+use crate::common::test_rng;
+use etf::distributions::CentralNormal;
+use etf::mcmc::{metropolis_hastings_step, random_walk_mh_kernel};
+
+// Log-density, up to a constant, of an equal-weight mixture of normal
+// distributions with means ±2 and standard deviation 0.5.
+fn bimodal_log_pdf(x: f64) -> f64 {
+    let a = (-2.0 * (x - 2.0) * (x - 2.0)).exp();
+    let b = (-2.0 * (x + 2.0) * (x + 2.0)).exp();
+
+    (a + b).ln()
+}
+
+// Checks the moments and the mode balance of a chain sampling the bimodal
+// target.
+fn check_bimodal_chain<K: FnMut(f64) -> f64>(mut next: K) {
+    let burn_in = 10_000;
+    let sample_count = 2_000_000;
+
+    let mut x = 0.0;
+    for _ in 0..burn_in {
+        x = next(x);
+    }
+
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    let mut positive = 0u64;
+    for _ in 0..sample_count {
+        x = next(x);
+        sum += x;
+        sum_sq += x * x;
+        if x > 0.0 {
+            positive += 1;
+        }
+    }
+
+    let mean = sum / sample_count as f64;
+    let second_moment = sum_sq / sample_count as f64;
+    let positive_fraction = positive as f64 / sample_count as f64;
+
+    // The samples are correlated so the tolerances are loose.
+    assert!(mean.abs() < 0.05, "mean: {}", mean);
+    assert!(
+        (second_moment - 4.25).abs() < 0.05,
+        "second moment: {}",
+        second_moment
+    );
+    assert!(
+        (positive_fraction - 0.5).abs() < 0.02,
+        "positive fraction: {}",
+        positive_fraction
+    );
+}
+
+#[test]
+fn metropolis_hastings_bimodal() {
+    let proposal = CentralNormal::new(2.0).unwrap();
+    let mut rng = test_rng();
+
+    check_bimodal_chain(|x| metropolis_hastings_step(x, &bimodal_log_pdf, &proposal, &mut rng));
+}
+
+#[test]
+fn random_walk_mh_kernel_bimodal() {
+    let kernel = random_walk_mh_kernel(bimodal_log_pdf, 2.0).unwrap();
+    let mut rng = test_rng();
+
+    check_bimodal_chain(|x| kernel(x, &mut rng));
+}
+
+#[test]
+fn random_walk_mh_kernel_bad_step_size() {
+    assert!(
+        random_walk_mh_kernel::<f64, _, rand_xoshiro::Xoshiro256StarStar>(bimodal_log_pdf, -1.0)
+            .is_err()
+    );
+}
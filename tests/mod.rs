@@ -1,2 +1,4 @@
 mod common;
-mod distributions;
\ No newline at end of file
+mod distributions;
+mod mcmc;
+mod primitives;
@@ -0,0 +1,173 @@
+use crate::common::{fair_goodness_of_fit, goodness_of_fit, test_rng};
+use etf::distributions::Normal;
+use etf::num::Float;
+use etf::primitives::partition::P128;
+use etf::primitives::util::{
+    histogram_tabulation, midpoint_prepartition, newton_tabulation, HistogramError, HistogramPdf,
+};
+use etf::primitives::{DistAny, Distribution};
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
+
+// Triangular distribution over [0, 1], vanishing at x = 1.
+//
+// The ratio `yinf/ysup` is 0 for the last subinterval, which can therefore
+// only use wedge sampling.
+fn make_triangular() -> DistAny<P128<f64>, f64, fn(f64) -> f64> {
+    let pdf = |x: f64| 1.0 - x;
+    let dpdf = |_: f64| -1.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, 1.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    assert_eq!(table.yinf[127], 0.0);
+
+    DistAny::new(pdf, &table)
+}
+
+#[test]
+fn dist_any_zero_yinf() {
+    let dist = make_triangular();
+    let mut rng = test_rng();
+
+    for _ in 0..1_000_000 {
+        let x = dist.sample(&mut rng);
+        assert!((0.0..=1.0).contains(&x));
+    }
+}
+
+#[test]
+fn dist_any_zero_yinf_fit() {
+    fair_goodness_of_fit(make_triangular(), |x| x * (2.0 - x), 10_000_000, 401, 0.01);
+}
+
+#[test]
+fn dist_any_retabulate() {
+    // Triangular distribution over [0, 1], vanishing at x = 0.
+    let pdf: fn(f64) -> f64 = |x| x;
+    let dpdf = |_: f64| 1.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, 1.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+
+    let mut dist = make_triangular();
+    dist.retabulate(pdf, &table);
+    let reference = DistAny::new(pdf, &table);
+
+    let mut rng = test_rng();
+    let mut reference_rng = test_rng();
+    for _ in 0..100_000 {
+        assert_eq!(dist.sample(&mut rng), reference.sample(&mut reference_rng));
+    }
+}
+
+#[test]
+fn dist_any_efficiency_estimate() {
+    let pdf = |x: f64| 1.0 - x;
+    let dpdf = |_: f64| -1.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, 1.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let dist = DistAny::new(pdf, &table);
+
+    // Subintervals have equal areas so the expected efficiency is the plain
+    // average of the `yinf/ysup` ratios.
+    let expected = (0..128).map(|i| table.yinf[i] / table.ysup[i]).sum::<f64>() / 128.0;
+    assert!((dist.efficiency_estimate() - expected).abs() < 1.0e-9);
+}
+
+// CDF of the histogram with edges [0, 1, 3, 4] and counts [1, 0, 3].
+fn sparse_histogram_cdf(x: f64) -> f64 {
+    if x < 0.0 {
+        0.0
+    } else if x < 1.0 {
+        0.25 * x
+    } else if x < 3.0 {
+        0.25
+    } else if x < 4.0 {
+        0.25 + 0.75 * (x - 3.0)
+    } else {
+        1.0
+    }
+}
+
+#[test]
+fn dist_any_from_histogram_fit() {
+    // Empty bins and fewer bins than partition sub-intervals.
+    let dist =
+        DistAny::<P128<f64>, f64, _>::from_histogram(&[0.0, 1.0, 3.0, 4.0], &[1, 0, 3]).unwrap();
+    let mut rng = test_rng();
+    for _ in 0..1_000_000 {
+        let x = dist.sample(&mut rng);
+        assert!((0.0..1.0).contains(&x) || (3.0..4.0).contains(&x));
+    }
+
+    fair_goodness_of_fit(dist, sparse_histogram_cdf, 10_000_000, 401, 0.01);
+}
+
+#[test]
+fn dist_any_from_histogram_normal() {
+    let normal_cdf = |x: f64| 0.5 * (1.0 + Float::erf(x * std::f64::consts::FRAC_1_SQRT_2));
+
+    // Histogram of normal samples with more bins than partition
+    // sub-intervals, generated with an independent RNG.
+    let bin_count = 500;
+    let (x0, x1) = (-5.0, 5.0);
+    let edges: Vec<f64> = (0..=bin_count)
+        .map(|i| x0 + (x1 - x0) * i as f64 / bin_count as f64)
+        .collect();
+    let mut counts = vec![0u64; bin_count];
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let mut rng = Xoshiro256StarStar::seed_from_u64(0x5eed);
+    for _ in 0..20_000_000 {
+        let x = normal.sample(&mut rng);
+        if x >= x0 && x < x1 {
+            counts[((x - x0) / (x1 - x0) * bin_count as f64) as usize] += 1;
+        }
+    }
+
+    // The histogram noise is negligible with a much smaller sample count
+    // and coarser bins.
+    let dist = DistAny::<P128<f64>, f64, _>::from_histogram(&edges, &counts).unwrap();
+    goodness_of_fit(dist, normal_cdf, x0, x1, 1_000_000, 50, 0.01);
+}
+
+#[test]
+fn dist_any_from_histogram_narrow_bin() {
+    // The bin only spans 4 floating point intervals, so most nodes of the
+    // partition coincide, including the first two.
+    let edges = [1.0, 1.0 + 4.0 * f64::EPSILON];
+    let pdf = HistogramPdf::new(&edges, &[1]).unwrap();
+    let table = histogram_tabulation::<P128<f64>, _>(&pdf);
+    assert_eq!(table.x[0], table.x[1]);
+    assert!((0..128).all(|i| table.ysup[i].is_finite()));
+
+    let dist = DistAny::<P128<f64>, f64, _>::from_histogram(&edges, &[1]).unwrap();
+    let mut rng = test_rng();
+    let mut sum = 0.0;
+    for _ in 0..100_000 {
+        let x = dist.sample(&mut rng);
+        assert!(x >= edges[0] && x <= edges[1]);
+        sum += (x - 1.0) / f64::EPSILON;
+    }
+    let mean = sum / 100_000.0;
+    assert!(mean > 1.5 && mean < 2.5);
+}
+
+#[test]
+fn dist_any_from_histogram_errors() {
+    type Hist = DistAny<P128<f64>, f64, HistogramPdf<f64>>;
+
+    assert!(matches!(
+        Hist::from_histogram(&[0.0, 1.0, 2.0], &[1, 2, 3]),
+        Err(HistogramError::MismatchedLengths)
+    ));
+    assert!(matches!(
+        Hist::from_histogram(&[0.0, 1.0, 1.0], &[1, 2]),
+        Err(HistogramError::UnorderedEdges)
+    ));
+    assert!(matches!(
+        Hist::from_histogram(&[0.0, 1.0, f64::INFINITY], &[1, 2]),
+        Err(HistogramError::UnorderedEdges)
+    ));
+    assert!(matches!(
+        Hist::from_histogram(&[0.0, 1.0, 2.0], &[0, 0]),
+        Err(HistogramError::ZeroCount)
+    ));
+}
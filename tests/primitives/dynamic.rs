@@ -0,0 +1,55 @@
+use crate::common::{fair_goodness_of_fit, test_rng};
+use etf::primitives::partition::P128;
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation, WeibullEnvelope};
+use etf::primitives::{DistAnyTailed, Distribution, DynDistribution, DynTryDistribution};
+
+fn pdf(x: f64) -> f64 {
+    (-x).exp()
+}
+
+fn dpdf(x: f64) -> f64 {
+    -(-x).exp()
+}
+
+type Exponential = DistAnyTailed<P128<f64>, f64, fn(f64) -> f64, Box<dyn DynTryDistribution<f64>>>;
+
+// Exponential distribution with a type-erased tail envelope.
+fn make_exponential() -> Exponential {
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+
+    // This envelope coincides with the PDF beyond the cut-in position.
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf as fn(f64) -> f64);
+    let tail_area = tail.area();
+
+    DistAnyTailed::new(pdf, &table, Box::new(tail), tail_area)
+}
+
+#[test]
+fn boxed_tail_envelope_fit() {
+    fair_goodness_of_fit(
+        make_exponential(),
+        |x| 1.0 - (-x).exp(),
+        10_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn boxed_distribution() {
+    let dists: Vec<Box<dyn DynDistribution<f64>>> =
+        vec![Box::new(make_exponential()), Box::new(make_exponential())];
+    let mut rng_dyn = test_rng();
+    let mut rng = test_rng();
+    let reference = make_exponential();
+
+    for dist in dists.iter() {
+        for _ in 0..1000 {
+            let x = dist.sample(&mut rng_dyn);
+            let x_ref = reference.sample(&mut rng);
+            assert_eq!(x, x_ref);
+        }
+    }
+}
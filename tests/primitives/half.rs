@@ -0,0 +1,122 @@
+use crate::common::fair_goodness_of_fit;
+use etf::primitives::partition::{InitTable, P128};
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation, WeibullEnvelope};
+use etf::primitives::{DistHalfAny, DistHalfAnyTailed, HalfDistError};
+
+// Exponential distribution shifted to [1, ∞).
+fn shifted_exp_pdf(x: f64) -> f64 {
+    (1.0 - x).exp()
+}
+
+fn shifted_exp_dpdf(x: f64) -> f64 {
+    -(1.0 - x).exp()
+}
+
+// Gamma distribution with shape 3 and unit scale.
+fn gamma_pdf(x: f64) -> f64 {
+    0.5 * x * x * (-x).exp()
+}
+
+fn gamma_dpdf(x: f64) -> f64 {
+    0.5 * x * (2.0 - x) * (-x).exp()
+}
+
+fn gamma_cdf(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    1.0 - (-x).exp() * (1.0 + x + 0.5 * x * x)
+}
+
+#[test]
+fn dist_half_any_exponential_fit() {
+    let (x_left, x_right) = (1.0, 20.0);
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&shifted_exp_pdf, x_left, x_right, 0);
+    let table = newton_tabulation(
+        &shifted_exp_pdf,
+        &shifted_exp_dpdf,
+        &x_init,
+        &[],
+        1.0e-8,
+        1.0,
+        50,
+    )
+    .unwrap();
+    let dist = DistHalfAny::new(x_left, shifted_exp_pdf, &table).unwrap();
+    assert_eq!(dist.x_left(), x_left);
+
+    // The truncation at `x_right` is negligible.
+    fair_goodness_of_fit(
+        dist,
+        |x| 1.0 - (1.0 - x.max(x_left)).exp(),
+        10_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn dist_half_any_tailed_gamma_fit() {
+    let tail_pos = 10.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&gamma_pdf, 0.0, tail_pos, 0);
+    let table =
+        newton_tabulation(&gamma_pdf, &gamma_dpdf, &x_init, &[2.0], 1.0e-8, 1.0, 50).unwrap();
+
+    // Since `x² exp(-x/2) < 1` for `x ≥ 10`, the envelope `exp(-x/2) / 2`
+    // lies above the PDF.
+    let tail = WeibullEnvelope::new(1.0, 1.0, 2.0, 0.0, tail_pos, gamma_pdf);
+    let dist = DistHalfAnyTailed::new(0.0, gamma_pdf, &table, tail, tail.area()).unwrap();
+
+    fair_goodness_of_fit(dist, gamma_cdf, 10_000_000, 401, 0.01);
+}
+
+#[test]
+fn dist_half_any_validation() {
+    // Two-sided normal function tabulated over [-4, 4].
+    let pdf = |x: f64| (-0.5 * x * x).exp();
+    let dpdf = |x: f64| -x * (-0.5 * x * x).exp();
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, -4.0, 4.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[0.0], 1.0e-8, 1.0, 50).unwrap();
+
+    assert!(matches!(
+        DistHalfAny::new(0.0, pdf, &table),
+        Err(HalfDistError::TableOutsideSupport)
+    ));
+    assert!(matches!(
+        DistHalfAny::new(-4.0, pdf, &table),
+        Err(HalfDistError::BadLeftBoundary)
+    ));
+    assert!(matches!(
+        DistHalfAny::new(f64::NAN, pdf, &table),
+        Err(HalfDistError::BadLeftBoundary)
+    ));
+
+    // Table given from right to left, with the last node left of `x_left`.
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&gamma_pdf, 0.0, 10.0, 0);
+    let table =
+        newton_tabulation(&gamma_pdf, &gamma_dpdf, &x_init, &[2.0], 1.0e-8, 1.0, 50).unwrap();
+    let mut reversed_table = InitTable::<P128<f64>, f64>::default();
+    for i in 0..=128 {
+        reversed_table.x[i] = table.x[128 - i];
+    }
+    for i in 0..128 {
+        reversed_table.yinf[i] = table.yinf[127 - i];
+        reversed_table.ysup[i] = table.ysup[127 - i];
+    }
+    assert!(matches!(
+        DistHalfAny::new(1.0, gamma_pdf, &reversed_table),
+        Err(HalfDistError::TableOutsideSupport)
+    ));
+    assert!(DistHalfAny::new(0.0, gamma_pdf, &reversed_table).is_ok());
+
+    // Function which is negative over part of the table.
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&gamma_pdf, 0.0, 10.0, 0);
+    let table =
+        newton_tabulation(&gamma_pdf, &gamma_dpdf, &x_init, &[2.0], 1.0e-8, 1.0, 50).unwrap();
+    let negative_pdf = |x: f64| if x < 5.0 { gamma_pdf(x) } else { -gamma_pdf(x) };
+    assert!(matches!(
+        DistHalfAny::new(0.0, negative_pdf, &table),
+        Err(HalfDistError::NegativePdf)
+    ));
+    assert!(DistHalfAny::new(0.0, gamma_pdf, &table).is_ok());
+}
@@ -0,0 +1,54 @@
+use crate::common::{fair_goodness_of_fit, test_rng};
+use etf::distributions::Normal;
+use etf::num::Float;
+use etf::primitives::{wrap_rand, Distribution, EtfDistWrapper};
+use rand::Rng;
+use rand_core::RngCore;
+
+// Mixture of two distributions with weights `w` and `1-w`.
+struct Mixture<D1, D2> {
+    d1: D1,
+    d2: D2,
+    w: f64,
+}
+
+impl<D1: Distribution<f64>, D2: Distribution<f64>> Distribution<f64> for Mixture<D1, D2> {
+    fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> f64 {
+        if f64::gen(rng) < self.w {
+            self.d1.sample(rng)
+        } else {
+            self.d2.sample(rng)
+        }
+    }
+}
+
+fn normal_cdf(x: f64, mean: f64, std_dev: f64) -> f64 {
+    0.5 * (1.0 + Float::erf((x - mean) / std_dev * 0.5_f64.sqrt()))
+}
+
+#[test]
+fn etf_rand_mixture_fit() {
+    let dist = Mixture {
+        d1: Normal::new(-2.0, 1.0).unwrap(),
+        d2: wrap_rand(rand_distr::Normal::new(1.5, 0.5).unwrap()),
+        w: 0.3,
+    };
+
+    fair_goodness_of_fit(
+        dist,
+        |x| 0.3 * normal_cdf(x, -2.0, 1.0) + 0.7 * normal_cdf(x, 1.5, 0.5),
+        10_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn etf_dist_wrapper_sample_iter() {
+    let dist = EtfDistWrapper::new(Normal::new(3.0, 2.0).unwrap());
+    let sample_count = 1_000_000;
+
+    let sum: f64 = test_rng().sample_iter(dist).take(sample_count).sum();
+
+    assert!((sum / sample_count as f64 - 3.0).abs() < 0.01);
+}
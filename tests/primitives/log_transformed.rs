@@ -0,0 +1,36 @@
+use crate::common::fair_goodness_of_fit;
+use etf::primitives::partition::P128;
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation};
+use etf::primitives::{DistAny, LogTransformedDist, LogTransformedFn, UnivariateFn};
+
+#[test]
+fn log_transformed_fit() {
+    // Non-normalized PDF x^(-1/2) over [a, 1], which is singular at x=0 but
+    // transforms to the smooth function exp(y/2) over [ln(a), 0].
+    let a = 1.0e-8_f64;
+    let pdf = |x: f64| 1.0 / x.sqrt();
+    let log_pdf = LogTransformedFn::new(pdf);
+    let dlog_pdf = |y: f64| 0.5 * (0.5 * y).exp();
+
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&log_pdf, a.ln(), 0.0, 0);
+    let table = newton_tabulation(&log_pdf, &dlog_pdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let dist = LogTransformedDist::new(DistAny::new(log_pdf, &table));
+
+    fair_goodness_of_fit(
+        dist,
+        |x| (x.sqrt() - a.sqrt()) / (1.0 - a.sqrt()),
+        10_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn log_transformed_fn_eval() {
+    let log_pdf = LogTransformedFn::new(|x: f64| (-x).exp());
+
+    for &y in &[-5.0_f64, -0.3, 0.0, 1.2, 3.0] {
+        let expected = (y - y.exp()).exp();
+        assert!((log_pdf.eval(y) - expected).abs() <= 1.0e-15 * expected);
+    }
+}
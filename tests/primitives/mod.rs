@@ -0,0 +1,13 @@
+mod any;
+mod dynamic;
+mod half;
+#[cfg(feature = "rand_interop")]
+mod interop;
+mod log_transformed;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod partition;
+mod progressive;
+mod symmetric;
+mod tailed;
+mod util;
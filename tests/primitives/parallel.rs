@@ -0,0 +1,55 @@
+use crate::common::{chi_square_test, Histogram};
+use etf::distributions::Normal;
+use etf::num::Float;
+use etf::primitives::sample_batch_into_par;
+use rand_pcg::Lcg128Xsl64;
+
+const SEEDS: [u64; 4] = [
+    0x9e3779b97f4a7c15,
+    0xbf58476d1ce4e5b9,
+    0x94d049bb133111eb,
+    42,
+];
+
+fn normal_cdf(x: f64, mean: f64, std_dev: f64) -> f64 {
+    0.5 * (1.0 + Float::erf((0.5_f64).sqrt() * (x - mean) / std_dev))
+}
+
+#[test]
+fn sample_batch_into_par_reproducible() {
+    let dist = Normal::new(-1.7, 2.8).unwrap();
+
+    // Use a length that is not a multiple of the number of seeds.
+    let mut out1 = vec![0.0f64; 100_003];
+    let mut out2 = vec![0.0f64; 100_003];
+    sample_batch_into_par::<_, _, Lcg128Xsl64>(&dist, &SEEDS, &mut out1);
+    sample_batch_into_par::<_, _, Lcg128Xsl64>(&dist, &SEEDS, &mut out2);
+    assert_eq!(out1, out2);
+
+    // Chunks seeded differently should not be identical.
+    let chunk_len = out1.len().div_ceil(SEEDS.len());
+    assert_ne!(out1[..100], out1[chunk_len..chunk_len + 100]);
+
+    let mut out3 = vec![0.0f64; 100_003];
+    sample_batch_into_par::<_, _, Lcg128Xsl64>(&dist, &SEEDS[..3], &mut out3);
+    assert_ne!(out1, out3);
+}
+
+#[test]
+fn sample_batch_into_par_fit() {
+    let mean = -1.7;
+    let std_dev = 2.8;
+    let dist = Normal::new(mean, std_dev).unwrap();
+
+    let mut out = vec![0.0f64; 10_000_000];
+    sample_batch_into_par::<_, _, Lcg128Xsl64>(&dist, &SEEDS, &mut out);
+
+    let mut histogram = Histogram::new(0.0, 1.0, 401);
+    for &x in out.iter() {
+        histogram.add(normal_cdf(x, mean, std_dev));
+    }
+    let p_value = chi_square_test(histogram, |x| x);
+    println!("P-value: {}", p_value);
+
+    assert!(p_value > 0.01);
+}
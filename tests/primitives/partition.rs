@@ -0,0 +1,191 @@
+use etf::primitives::partition::*;
+
+// The partition constants and constructors are usable in const context.
+const P256_BITS: u32 = P256::<f64>::BITS;
+const P256_SIZE: usize = <P256<f64> as Partition<f64>>::SIZE;
+const P4096_MARKER: P4096<f32> = P4096::new();
+
+const _: () = assert!(P256_BITS == 8);
+const _: () = assert!(P256_SIZE == 256);
+
+#[test]
+fn partition_constants() {
+    let _ = P4096_MARKER;
+
+    assert_eq!(<P16<f64> as Partition<f64>>::SIZE, 16);
+    assert_eq!(<P32<f64> as Partition<f64>>::SIZE, 32);
+    assert_eq!(<P64<f64> as Partition<f64>>::SIZE, 64);
+    assert_eq!(<P128<f64> as Partition<f64>>::SIZE, 128);
+    assert_eq!(<P256<f64> as Partition<f64>>::SIZE, 256);
+    assert_eq!(<P512<f64> as Partition<f64>>::SIZE, 512);
+    assert_eq!(<P1024<f64> as Partition<f64>>::SIZE, 1024);
+    assert_eq!(<P2048<f64> as Partition<f64>>::SIZE, 2048);
+    assert_eq!(<P4096<f32> as Partition<f32>>::SIZE, 4096);
+    assert_eq!(<P4096<f32> as Partition<f32>>::BITS, 12);
+}
+
+#[test]
+fn init_table_accessors() {
+    let mut table = InitTable::<P16<f64>, f64>::default();
+    for i in 0..=16 {
+        table.x[i] = i as f64;
+    }
+    for i in 0..16 {
+        table.yinf[i] = 2.0 * i as f64;
+        table.ysup[i] = 3.0 * i as f64;
+    }
+
+    for i in 0..=16 {
+        assert_eq!(table.node(i), i as f64);
+    }
+    for i in 0..16 {
+        assert_eq!(table.lower_bound(i), 2.0 * i as f64);
+        assert_eq!(table.upper_bound(i), 3.0 * i as f64);
+    }
+
+    let rows: Vec<(f64, f64, f64)> = table.iter().collect();
+    assert_eq!(rows.len(), 16);
+    for (i, &row) in rows.iter().enumerate() {
+        let x = i as f64;
+        assert_eq!(row, (x, 2.0 * x, 3.0 * x));
+    }
+}
+
+#[test]
+fn partition_ordering() {
+    assert!(P32::<f64>::is_larger_than::<P16<f64>>());
+    assert!(P4096::<f64>::is_larger_than::<P2048<f64>>());
+    assert!(!P16::<f64>::is_larger_than::<P32<f64>>());
+    assert!(!P512::<f32>::is_larger_than::<P512<f32>>());
+
+    const _: () = assert!(P16::<f64>::BITS < P32::<f64>::BITS);
+}
+
+#[test]
+fn quantile_table_exponential() {
+    use etf::primitives::util::{midpoint_prepartition, newton_tabulation};
+
+    // Unit exponential distribution restricted to [0, 4].
+    let pdf = |x: f64| (-x).exp();
+    let dpdf = |x: f64| -(-x).exp();
+    let cdf = |x: f64| 1.0 - (-x).exp();
+    let x_max = 4.0;
+    let quantile = |p: f64| -(1.0 - p * cdf(x_max)).ln();
+
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, x_max, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let quantiles = QuantileTable::from_init_table(&table, &cdf);
+
+    assert_eq!(quantiles.quantile(0), 0.0);
+    assert_eq!(quantiles.quantile(128), x_max);
+    for i in 0..=128 {
+        let p = i as f64 / 128.0;
+        assert!((quantiles.quantile(i) - quantile(p)).abs() < 1.0e-12);
+    }
+
+    for i in 0..=1000 {
+        let p = i as f64 / 1000.0;
+        let exact = quantile(p);
+        assert!((quantiles.quantile_approx(p) - exact).abs() < 5.0e-2);
+        assert!((quantiles.quantile_refined(p, &cdf, &pdf) - exact).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn init_table_diff() {
+    use etf::primitives::util::{midpoint_prepartition, newton_tabulation};
+
+    // Table of a normal density over [mean - 4, mean + 4].
+    let make_table = |mean: f64| {
+        let pdf = move |x: f64| (-0.5 * (x - mean) * (x - mean)).exp();
+        let dpdf = move |x: f64| -(x - mean) * (-0.5 * (x - mean) * (x - mean)).exp();
+        let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, mean - 4.0, mean + 4.0, 0);
+
+        newton_tabulation(&pdf, &dpdf, &x_init, &[mean], 1.0e-10, 1.0, 50).unwrap()
+    };
+
+    let reference = make_table(0.0);
+    let diff1 = reference.diff(&make_table(1.0e-4));
+    let diff2 = reference.diff(&make_table(2.0e-4));
+
+    assert!((diff1.max_x_diff / 1.0e-4 - 1.0).abs() < 1.0e-3);
+    assert!((diff2.max_x_diff / diff1.max_x_diff - 2.0).abs() < 1.0e-3);
+    assert!(diff1.max_yinf_diff < 1.0e-6);
+    assert!(diff1.max_ysup_diff < 1.0e-6);
+
+    let diff0 = reference.diff(&reference);
+    assert_eq!(diff0.max_x_diff, 0.0);
+    assert_eq!(diff0.max_x_index, 0);
+    assert!(!format!("{}", diff0).is_empty());
+
+    assert!(reference.is_close(&reference.clone(), 0.0));
+    assert!(reference.is_close(&make_table(1.0e-4), 2.0e-4));
+    assert!(!reference.is_close(&make_table(1.0e-4), 0.5e-4));
+
+    // NaN values are never close.
+    let mut nan_table = reference.clone();
+    nan_table.yinf[3] = f64::NAN;
+    nan_table.ysup[5] = f64::NAN;
+    let nan_diff = reference.diff(&nan_table);
+    assert!(nan_diff.max_yinf_diff.is_nan());
+    assert_eq!(nan_diff.max_yinf_index, 3);
+    assert!(nan_diff.max_ysup_diff.is_nan());
+    assert_eq!(nan_diff.max_ysup_index, 5);
+    assert!(!reference.is_close(&nan_table, f64::INFINITY));
+    assert!(!nan_table.is_close(&nan_table, f64::INFINITY));
+
+    // Nor are infinite differences.
+    let mut inf_table = reference.clone();
+    inf_table.ysup[5] = f64::INFINITY;
+    assert!(!reference.is_close(&inf_table, f64::INFINITY));
+}
+
+// Uniform function over [0, 1] with a peak over the first subinterval.
+fn make_step_table() -> InitTable<P16<f64>, f64> {
+    let mut table = InitTable::<P16<f64>, f64>::default();
+    for i in 0..=16 {
+        table.x[i] = i as f64 / 16.0;
+    }
+    for i in 0..16 {
+        table.yinf[i] = 1.0;
+        table.ysup[i] = 1.0;
+    }
+    table.ysup[0] = 2.0;
+
+    table
+}
+
+#[test]
+fn init_table_display() {
+    let table = make_step_table();
+
+    let output = format!("{}", table);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 16 + 1);
+    assert!(lines[0].starts_with('i'));
+    assert_eq!(
+        lines[1].split_whitespace().collect::<Vec<_>>(),
+        ["0", "0.0000", "0.0625", "1.0000", "2.0000"]
+    );
+
+    let output = format!("{:.1}", table);
+    assert_eq!(
+        output
+            .lines()
+            .nth(16)
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>(),
+        ["15", "0.9", "1.0", "1.0", "1.0"]
+    );
+}
+
+#[cfg(feature = "debug_utils")]
+#[test]
+fn init_table_plot_ascii() {
+    let plot = make_step_table().plot_ascii(8, 4);
+    let lines: Vec<&str> = plot.lines().collect();
+
+    assert_eq!(lines, ["#       ", "#       ", "########", "########"]);
+    assert!(make_step_table().plot_ascii(0, 4).is_empty());
+}
@@ -0,0 +1,125 @@
+use crate::common::{fair_goodness_of_fit, test_rng};
+use etf::num::Float;
+use etf::primitives::partition::{InitTable, P16};
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation};
+use etf::primitives::{Distribution, ProgressiveDistAny};
+
+fn pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+fn dpdf(x: f64) -> f64 {
+    -x * (-0.5 * x * x).exp()
+}
+
+// Normal distribution truncated to [-4, 4], initialized with a coarse table.
+fn make_truncated_normal(refine_period: u64) -> ProgressiveDistAny<P16<f64>, f64, fn(f64) -> f64> {
+    let x_init = midpoint_prepartition::<P16<f64>, _, _>(&pdf, -4.0, 4.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[0.0], 1.0e-8, 1.0, 50).unwrap();
+
+    ProgressiveDistAny::new(pdf, &table, &[0.0], refine_period)
+}
+
+// Constant function with a narrow triangular spike at 0.
+fn spike(x: f64) -> f64 {
+    0.1 + 0.9 * (1.0 - 25.0 * x.abs()).max(0.0)
+}
+
+// Spike distribution truncated to [-1.3, 2.7], initialized with a table of
+// uniformly spaced nodes in decreasing order.
+//
+// The spike lies well within a rectangle rather than on a node, so a
+// sub-rectangle containing it is only bounded correctly if the extremum is
+// accounted for; otherwise it is bounded by the constant and never rejects,
+// so the spike is permanently truncated.
+fn make_reversed_spike(refine_period: u64) -> ProgressiveDistAny<P16<f64>, f64, fn(f64) -> f64> {
+    let mut table = InitTable::<P16<f64>, f64>::default();
+    for i in 0..=16 {
+        table.x[i] = 2.7 - 0.25 * i as f64;
+    }
+    for i in 0..16 {
+        let (y0, y1) = (spike(table.x[i]), spike(table.x[i + 1]));
+        table.yinf[i] = y0.min(y1);
+        table.ysup[i] = if table.x[i] > 0.0 && table.x[i + 1] < 0.0 {
+            1.0
+        } else {
+            y0.max(y1)
+        };
+    }
+
+    ProgressiveDistAny::new(spike, &table, &[0.0], refine_period)
+}
+
+fn spike_cdf(x: f64) -> f64 {
+    let primitive = |x: f64| {
+        let spike_primitive = if x < -0.04 {
+            0.0
+        } else if x < 0.0 {
+            12.5 * (x + 0.04) * (x + 0.04)
+        } else if x < 0.04 {
+            0.04 - 12.5 * (0.04 - x) * (0.04 - x)
+        } else {
+            0.04
+        };
+
+        0.1 * x + 0.9 * spike_primitive
+    };
+
+    (primitive(x) - primitive(-1.3)) / (primitive(2.7) - primitive(-1.3))
+}
+
+fn truncated_normal_cdf(x: f64) -> f64 {
+    let phi = |x: f64| 0.5 * (1.0 + Float::erf(x * 0.5_f64.sqrt()));
+
+    (phi(x) - phi(-4.0)) / (phi(4.0) - phi(-4.0))
+}
+
+#[test]
+fn progressive_dist_any_fit() {
+    fair_goodness_of_fit(
+        make_truncated_normal(10_000),
+        truncated_normal_cdf,
+        10_000_000,
+        401,
+        0.01,
+    );
+}
+
+#[test]
+fn progressive_dist_any_reversed_fit() {
+    fair_goodness_of_fit(make_reversed_spike(1_000), spike_cdf, 10_000_000, 401, 0.01);
+}
+
+#[test]
+fn progressive_dist_any_refinement() {
+    let dist = make_truncated_normal(1_000);
+    let mut rng = test_rng();
+    let initial_len = dist.len();
+    let initial_efficiency = dist.efficiency();
+
+    for _ in 0..100_000 {
+        let x = dist.sample(&mut rng);
+        assert!((-4.0..=4.0).contains(&x));
+    }
+
+    assert!(dist.len() > initial_len);
+    assert!(dist.efficiency() > initial_efficiency);
+    assert!(dist.efficiency() < 1.0);
+}
+
+#[test]
+fn progressive_dist_any_bounded_len() {
+    let x_init = midpoint_prepartition::<P16<f64>, _, _>(&pdf, -4.0, 4.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[0.0], 1.0e-8, 1.0, 50).unwrap();
+    let bounded = ProgressiveDistAny::with_max_len(pdf, &table, &[0.0], 10, 40);
+    let default_bounded = make_truncated_normal(10);
+    let mut rng = test_rng();
+
+    for _ in 0..1_000_000 {
+        bounded.sample(&mut rng);
+        default_bounded.sample(&mut rng);
+    }
+
+    assert_eq!(bounded.len(), 40);
+    assert!(default_bounded.len() <= 64 * 16);
+}
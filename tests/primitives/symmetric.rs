@@ -0,0 +1,199 @@
+use crate::common::{fair_goodness_of_fit, test_rng};
+use etf::primitives::partition::{InitTable, P256};
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation, WeibullEnvelope};
+use etf::primitives::{DistSymmetric, DistSymmetricTailed, Distribution};
+
+// Symmetric triangular distribution over [x0 - 1, x0 + 1].
+fn make_triangular(x0: f64) -> DistSymmetric<P256<f64>, f64, impl Fn(f64) -> f64 + Copy> {
+    let pdf = move |x: f64| 1.0 - (x - x0);
+    let dpdf = |_: f64| -1.0;
+    let x_init = midpoint_prepartition::<P256<f64>, _, _>(&pdf, x0, x0 + 1.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+
+    DistSymmetric::new(x0, pdf, &table)
+}
+
+// Symmetric uniform distribution over [x0 - width, x0 + width].
+fn make_uniform<T: etf::num::Float>(x0: T, width: T) -> DistSymmetric<P256<T>, T, fn(T) -> T> {
+    let mut table = InitTable::<P256<T>, T>::default();
+    for i in 0..=256 {
+        table.x[i] = x0 + width * T::cast_usize(i) / T::cast_usize(256);
+    }
+    for i in 0..256 {
+        table.yinf[i] = T::ONE;
+        table.ysup[i] = T::ONE;
+    }
+
+    DistSymmetric::new(x0, |_| T::ONE, &table)
+}
+
+#[test]
+fn dist_symmetric_negative_x0_fit() {
+    let x0 = -10.0;
+    let cdf = move |x: f64| {
+        let z = x - x0;
+        if z < 0.0 {
+            0.5 * (1.0 + z) * (1.0 + z)
+        } else {
+            1.0 - 0.5 * (1.0 - z) * (1.0 - z)
+        }
+    };
+
+    fair_goodness_of_fit(make_triangular(x0), cdf, 50_000_000, 401, 0.01);
+}
+
+#[test]
+fn dist_symmetric_negative_x0_symmetry() {
+    let x0 = -10.0;
+    let dist = make_triangular(x0);
+    let mut rng = test_rng();
+    let sample_count = 10_000_000;
+
+    let mut above = 0u64;
+    for _ in 0..sample_count {
+        let x = dist.sample(&mut rng);
+        assert!(x >= x0 - 1.0 && x <= x0 + 1.0);
+        if x > x0 {
+            above += 1;
+        }
+    }
+
+    // Allow for 5 standard deviations.
+    let expected = 0.5 * sample_count as f64;
+    let sigma = (0.25 * sample_count as f64).sqrt();
+    assert!((above as f64 - expected).abs() < 5.0 * sigma);
+}
+
+#[test]
+fn dist_symmetric_subnormal_delta() {
+    // All values of `delta_x` are subnormal.
+    let width = 1.0e-310;
+    let mut rng = test_rng();
+
+    // With x0 = 0, subnormal samples must be symmetric about 0.
+    let dist = make_uniform(0.0, width);
+    let sample_count = 1_000_000;
+    let mut negative = 0u64;
+    for _ in 0..sample_count {
+        let x: f64 = dist.sample(&mut rng);
+        assert!(x.abs() <= width);
+        if x.is_sign_negative() {
+            negative += 1;
+        }
+    }
+    let expected = 0.5 * sample_count as f64;
+    let sigma = (0.25 * sample_count as f64).sqrt();
+    assert!((negative as f64 - expected).abs() < 5.0 * sigma);
+
+    // With x0 = -10, subnormal offsets are absorbed by x0.
+    let x0 = -10.0;
+    let dist = make_uniform(x0, width);
+    for _ in 0..sample_count {
+        assert_eq!(dist.sample(&mut rng), x0);
+    }
+}
+
+#[test]
+fn dist_symmetric_large_x0() {
+    // At such magnitudes the table nodes are rounded to a few ulps, so only
+    // boundedness and symmetry are checked rather than the goodness of fit.
+    let x0 = f32::MAX / 2.0;
+    let width = x0 * 1.0e-3;
+    let dist = make_uniform(x0, width);
+    let mut rng = test_rng();
+    let sample_count = 10_000_000;
+
+    let mut above = 0u64;
+    let mut sum = 0.0;
+    for _ in 0..sample_count {
+        let x = dist.sample(&mut rng);
+        assert!(x.is_finite());
+        assert!(x >= x0 - width && x <= x0 + width);
+        if x > x0 {
+            above += 1;
+        }
+        sum += (x as f64 - x0 as f64) / width as f64;
+    }
+
+    let expected = 0.5 * sample_count as f64;
+    let sigma = (0.25 * sample_count as f64).sqrt();
+    assert!((above as f64 - expected).abs() < 5.0 * sigma);
+    let mean_sigma = (1.0 / (3.0 * sample_count as f64)).sqrt();
+    assert!((sum / sample_count as f64).abs() < 5.0 * mean_sigma);
+}
+
+#[test]
+fn dist_symmetric_efficiency_estimate() {
+    let dist = make_uniform(-1.0f64, 2.0);
+    assert!((dist.efficiency_estimate() - 1.0).abs() < 1.0e-12);
+
+    let dist = make_uniform(0.5f32, 3.0);
+    assert!((dist.efficiency_estimate() - 1.0).abs() < 1.0e-6);
+}
+
+type Func = fn(f64) -> f64;
+
+// Laplace distribution about x0 = 10 with a rejection-sampled tail beyond
+// x0 ± 5, generated either from a right tail or from a mirrored left tail
+// envelope.
+fn make_shifted_laplace(
+    mirrored_envelope: bool,
+) -> DistSymmetricTailed<P256<f64>, f64, Func, WeibullEnvelope<f64, Func>> {
+    let x0 = 10.0;
+    let pdf: Func = |x| (-(x - 10.0f64).abs()).exp();
+    let dpdf = |x: f64| -(-(x - 10.0)).exp();
+    let x_init = midpoint_prepartition::<P256<f64>, _, _>(&pdf, x0, x0 + 5.0, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = if mirrored_envelope {
+        WeibullEnvelope::new(1.0, 1.0, -1.0, x0, x0 - 5.0, pdf)
+    } else {
+        WeibullEnvelope::new(1.0, 1.0, 1.0, x0, x0 + 5.0, pdf)
+    };
+
+    DistSymmetricTailed::new(x0, pdf, &table, tail, tail.area())
+}
+
+#[test]
+fn dist_symmetric_tailed_shifted_x0_tail_symmetry() {
+    let x0 = 10.0;
+    let mut rng = test_rng();
+    let sample_count = 10_000_000;
+
+    // The tail is sampled with the same random sign as the table, so the
+    // samples are symmetric about x0 whichever side the envelope covers.
+    for &mirrored_envelope in &[false, true] {
+        let dist = make_shifted_laplace(mirrored_envelope);
+        let mut left_tail = 0u64;
+        let mut right_tail = 0u64;
+        for _ in 0..sample_count {
+            let x = dist.sample(&mut rng);
+            if x < x0 - 5.0 {
+                left_tail += 1;
+            } else if x > x0 + 5.0 {
+                right_tail += 1;
+            }
+        }
+
+        // Each tail holds a fraction exp(-5)/2 of the samples.
+        let p = 0.5 * (-5.0f64).exp();
+        let expected = p * sample_count as f64;
+        let sigma = (p * (1.0 - p) * sample_count as f64).sqrt();
+        assert!((left_tail as f64 - expected).abs() < 5.0 * sigma);
+        assert!((right_tail as f64 - expected).abs() < 5.0 * sigma);
+    }
+}
+
+#[test]
+fn dist_symmetric_tailed_shifted_x0_fit() {
+    let cdf = |x: f64| {
+        let z = x - 10.0;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    };
+
+    fair_goodness_of_fit(make_shifted_laplace(false), cdf, 20_000_000, 401, 0.01);
+    fair_goodness_of_fit(make_shifted_laplace(true), cdf, 20_000_000, 401, 0.01);
+}
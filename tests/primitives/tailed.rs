@@ -0,0 +1,136 @@
+use crate::common::{fair_goodness_of_fit, test_rng};
+use etf::primitives::partition::P128;
+use etf::primitives::util::{midpoint_prepartition, newton_tabulation, WeibullEnvelope};
+use etf::primitives::{DistAny, DistAnyTailed, Distribution};
+
+fn pdf(x: f64) -> f64 {
+    (-x).exp()
+}
+
+fn dpdf(x: f64) -> f64 {
+    -(-x).exp()
+}
+
+#[test]
+fn dist_any_tailed_extreme_tail_area() {
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf);
+    let mut rng = test_rng();
+
+    for &tail_area in &[1.0e20, f64::INFINITY] {
+        let dist = DistAnyTailed::new(pdf, &table, tail, tail_area);
+        for _ in 0..100_000 {
+            let x = dist.sample(&mut rng);
+            assert!(x.is_finite() && x >= 0.0);
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn dist_any_tailed_extreme_tail_area_warning() {
+    use std::cell::Cell;
+
+    // Counts the warnings emitted by this crate within the current thread,
+    // since other tests may run concurrently.
+    thread_local! {
+        static WARNING_COUNT: Cell<usize> = const { Cell::new(0) };
+    }
+    struct WarningCounter;
+    impl log::Log for WarningCounter {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Warn && metadata.target().starts_with("etf")
+        }
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                WARNING_COUNT.with(|count| count.set(count.get() + 1));
+            }
+        }
+        fn flush(&self) {}
+    }
+    log::set_logger(&WarningCounter).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf);
+
+    DistAnyTailed::new(pdf, &table, tail, tail.area());
+    assert_eq!(WARNING_COUNT.with(Cell::get), 0);
+    DistAnyTailed::new(pdf, &table, tail, 1.0e20);
+    assert_eq!(WARNING_COUNT.with(Cell::get), 1);
+}
+
+#[test]
+fn dist_any_tailed_from_bounded_dist() {
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf);
+    let tail_area = tail.area();
+
+    let bounded = DistAny::new(pdf, &table);
+    let dist = DistAnyTailed::from_bounded_dist(bounded, &table, tail, tail_area);
+    let reference = DistAnyTailed::new(pdf, &table, tail, tail_area);
+
+    let mut rng = test_rng();
+    let mut reference_rng = test_rng();
+    for _ in 0..100_000 {
+        assert_eq!(dist.sample(&mut rng), reference.sample(&mut reference_rng));
+    }
+}
+
+#[test]
+fn dist_any_tailed_clone_fit() {
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf);
+    let dist = DistAnyTailed::new(pdf, &table, tail, tail.area());
+    let cdf = |x: f64| 1.0 - (-x).exp();
+
+    let clone = dist.clone();
+    fair_goodness_of_fit(dist, cdf, 10_000_000, 401, 0.01);
+    fair_goodness_of_fit(clone, cdf, 10_000_000, 401, 0.01);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn dist_any_tailed_zero_tail_area() {
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf);
+
+    DistAnyTailed::new(pdf, &table, tail, 0.0);
+}
+
+#[test]
+fn dist_any_tailed_efficiency_estimate() {
+    let tail_pos = 2.0;
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&pdf, 0.0, tail_pos, 0);
+    let table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-8, 1.0, 50).unwrap();
+    let tail = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, tail_pos, pdf);
+    let table_area = (0..128)
+        .map(|i| (table.x[i + 1] - table.x[i]) * table.ysup[i])
+        .sum::<f64>();
+    let table_efficiency = DistAny::new(pdf, &table).efficiency_estimate();
+
+    // The rectangle test is only reached when the tail is not selected, so the
+    // efficiency of the table is weighted by the probability of the table.
+    for &tail_area in &[tail.area(), table_area, 10.0 * table_area] {
+        let dist = DistAnyTailed::new(pdf, &table, tail, tail_area);
+        let expected = table_efficiency * table_area / (table_area + tail_area);
+        assert!(
+            (dist.efficiency_estimate() - expected).abs() < 1.0e-9,
+            "tail area: {}, efficiency: {}, expected: {}",
+            tail_area,
+            dist.efficiency_estimate(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,559 @@
+use crate::common::test_rng;
+use etf::primitives::partition::{Partition, P128, P16, P256, P64};
+use etf::primitives::util::{
+    composite_prepartition, hermite_prepartition, logspace_prepartition, midpoint_prepartition,
+    newton_tabulation, pdf_area, pdf_area_adaptive, second_order_newton_tabulation,
+    try_midpoint_prepartition, uniform_prepartition, validate_envelope, Polynomial, SingularPdf,
+    SplineUnivariateFn, SumEnvelope, TabulationBuilder, WeibullEnvelope,
+};
+use etf::primitives::{TryDistribution, UnivariateFn};
+
+fn pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+fn dpdf(x: f64) -> f64 {
+    -x * (-0.5 * x * x).exp()
+}
+
+#[test]
+fn tabulation_builder_progress() {
+    let x_init = midpoint_prepartition::<P64<f64>, _, _>(&pdf, 0.0, 3.0, 0);
+    let mut errors = Vec::new();
+    let table = TabulationBuilder::new(&pdf, &dpdf, &x_init, &[])
+        .tolerance(1.0e-6)
+        .progress(|iter, error| errors.push((iter, error)))
+        .build()
+        .unwrap();
+    let reference_table = newton_tabulation(&pdf, &dpdf, &x_init, &[], 1.0e-6, 1.0, 50).unwrap();
+
+    assert!(errors.len() > 1);
+    for (i, &(iter, _)) in errors.iter().enumerate() {
+        assert_eq!(iter as usize, i);
+    }
+    assert!(errors.last().unwrap().1 < 1.0e-6);
+    for i in 0..=64 {
+        assert_eq!(table.x[i], reference_table.x[i]);
+    }
+}
+
+#[test]
+fn tabulation_builder_line_search_failure() {
+    // With a derivative of the wrong sign, the Newton update soon increases
+    // the residual whatever the step length.
+    let wrong_dpdf = |x: f64| -dpdf(x);
+    let x_init = midpoint_prepartition::<P64<f64>, _, _>(&pdf, 0.0, 3.0, 0);
+    let mut errors = Vec::new();
+    let table = TabulationBuilder::new(&pdf, &wrong_dpdf, &x_init, &[])
+        .tolerance(1.0e-6)
+        .max_iter(50)
+        .progress(|iter, error| errors.push((iter, error)))
+        .build();
+
+    // The tabulation fails before the maximum number of iterations.
+    assert!(table.is_err());
+    assert!(errors.len() < 50);
+}
+
+#[test]
+fn validate_weibull_envelope() {
+    // For x ≥ 1, the Rayleigh tail `x exp(-x²/2)` is above `exp(-x²/2)`.
+    let envelope = WeibullEnvelope::new(1.0, 2.0, 2.0_f64.sqrt(), 0.0, 1.0, pdf);
+    assert!(validate_envelope(&pdf, &envelope, 1.0, 10.0, 1000).is_ok());
+
+    // For x < 1, the Rayleigh tail is below `exp(-x²/2)`.
+    let rayleigh = |x: f64| x * (-0.5 * x * x).exp();
+    let err = validate_envelope(&pdf, &rayleigh, 0.5, 10.0, 1000).unwrap_err();
+    assert_eq!(err.x, 0.5);
+    assert!(err.pdf_value > err.envelope_value);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid Weibull envelope")]
+fn weibull_envelope_below_pdf() {
+    // For x < 1, the Rayleigh tail `x exp(-x²/2)` is below `exp(-x²/2)`.
+    WeibullEnvelope::new(1.0, 2.0, 2.0_f64.sqrt(), 0.0, 0.5, pdf);
+}
+
+#[test]
+fn polynomial_accuracy() {
+    let coefficients = vec![0.3, -1.7, 2.1, 0.05, -0.8, 0.4];
+    let p = Polynomial::new(coefficients.clone());
+    let dp = p.derivative();
+
+    for i in 0..=100 {
+        let x = -2.0 + 0.04 * i as f64;
+        let y: f64 = coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c * x.powi(k as i32))
+            .sum();
+        let dy: f64 = coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, c)| k as f64 * c * x.powi(k as i32 - 1))
+            .sum();
+
+        assert!((p.eval(x) - y).abs() <= 1.0e-12 * y.abs().max(1.0));
+        assert!((dp.eval(x) - dy).abs() <= 1.0e-12 * dy.abs().max(1.0));
+    }
+}
+
+#[test]
+fn spline_accuracy() {
+    let points: Vec<(f64, f64)> = (0..=160)
+        .map(|i| {
+            let x = -4.0 + 0.05 * i as f64;
+            (x, pdf(x))
+        })
+        .collect();
+    let spline = SplineUnivariateFn::new(&points).unwrap();
+    let dspline = spline.derivative();
+
+    for &(x, y) in &points {
+        assert!((spline.eval(x) - y).abs() <= 1.0e-12);
+    }
+    for i in 0..1000 {
+        let x = -3.5 + 0.007 * i as f64;
+        assert!((spline.eval(x) - pdf(x)).abs() <= 1.0e-5);
+        assert!((dspline.eval(x) - dpdf(x)).abs() <= 1.0e-3);
+    }
+}
+
+#[test]
+fn spline_linear() {
+    let points = [(0.0, 1.0), (0.5, 2.0), (2.0, 5.0), (3.0, 7.0)];
+    let spline = SplineUnivariateFn::new(&points).unwrap();
+    let dspline = spline.derivative();
+
+    for i in 0..=30 {
+        let x = 0.1 * i as f64;
+        assert!((spline.eval(x) - (1.0 + 2.0 * x)).abs() <= 1.0e-12);
+        assert!((dspline.eval(x) - 2.0).abs() <= 1.0e-12);
+    }
+}
+
+#[test]
+fn spline_errors() {
+    assert!(SplineUnivariateFn::new(&[(0.0, 1.0)]).is_err());
+    assert!(SplineUnivariateFn::new(&[(0.0, 1.0), (1.0, 2.0), (1.0, 3.0)]).is_err());
+}
+
+#[test]
+fn sum_envelope_selection() {
+    // Exponential tails that exactly match the Laplace PDF beyond their cut-in
+    // positions, with areas exp(-1) and exp(-2) respectively.
+    let laplace = |x: f64| (-x.abs()).exp();
+    let left = WeibullEnvelope::new(1.0, 1.0, -1.0, 0.0, -1.0, laplace);
+    let right = WeibullEnvelope::new(1.0, 1.0, 1.0, 0.0, 2.0, laplace);
+    let envelope = SumEnvelope::new(left, left.area(), right, right.area());
+    assert!((envelope.area() - ((-1.0_f64).exp() + (-2.0_f64).exp())).abs() <= 1.0e-12);
+
+    let mut rng = test_rng();
+    let sample_count = 1_000_000;
+    let mut left_count = 0u64;
+    for _ in 0..sample_count {
+        let x = envelope.try_sample(&mut rng).unwrap();
+        assert!(x <= -1.0 || x >= 2.0);
+        if x < 0.0 {
+            left_count += 1;
+        }
+    }
+    let p = envelope.area().recip() * (-1.0_f64).exp();
+    let expected = p * sample_count as f64;
+    let sigma = (p * (1.0 - p) * sample_count as f64).sqrt();
+    assert!((left_count as f64 - expected).abs() < 5.0 * sigma);
+
+    // Appending an envelope with a disjoint support adds its area.
+    let center = BoxEnvelope { x0: -1.0, x1: 2.0 };
+    let envelope = envelope + (center, 3.0);
+    let expected_area = (-1.0_f64).exp() + (-2.0_f64).exp() + 3.0;
+    assert!((envelope.area() - expected_area).abs() <= 1.0e-12);
+
+    // The accepted samples follow the Laplace PDF over the whole real line.
+    let mut center_count = 0u64;
+    for _ in 0..sample_count {
+        if let Some(x) = envelope.try_sample(&mut rng) {
+            if (-1.0..2.0).contains(&x) {
+                center_count += 1;
+            }
+        }
+    }
+    let p = (2.0 - (-1.0_f64).exp() - (-2.0_f64).exp()) / expected_area;
+    let expected = p * sample_count as f64;
+    let sigma = (p * (1.0 - p) * sample_count as f64).sqrt();
+    assert!((center_count as f64 - expected).abs() < 5.0 * sigma);
+}
+
+// Envelope of height 1 over [x0, x1) for the Laplace PDF.
+#[derive(Copy, Clone)]
+struct BoxEnvelope {
+    x0: f64,
+    x1: f64,
+}
+
+impl TryDistribution<f64> for BoxEnvelope {
+    fn try_sample<R: rand::RngCore + ?Sized>(&self, rng: &mut R) -> Option<f64> {
+        use rand::Rng;
+
+        let x = self.x0 + (self.x1 - self.x0) * rng.gen::<f64>();
+        if rng.gen::<f64>() <= (-x.abs()).exp() {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn uniform_prepartition_convergence() {
+    let (x0, x1) = (0.0, 4.0);
+
+    let x_init = uniform_prepartition::<P64<f64>, f64>(x0, x1);
+    assert_eq!(x_init[0], x0);
+    assert_eq!(x_init[64], x1);
+    for i in 0..=64 {
+        assert!((x_init[i] - i as f64 / 16.0).abs() < 1.0e-15);
+    }
+
+    let mut uniform_iter = 0;
+    TabulationBuilder::new(&pdf, &dpdf, &x_init, &[])
+        .max_iter(200)
+        .progress(|iter, _| uniform_iter = iter)
+        .build()
+        .unwrap();
+
+    let mut midpoint_iter = 0;
+    let x_init = midpoint_prepartition::<P64<f64>, _, _>(&pdf, x0, x1, 0);
+    TabulationBuilder::new(&pdf, &dpdf, &x_init, &[])
+        .max_iter(200)
+        .progress(|iter, _| midpoint_iter = iter)
+        .build()
+        .unwrap();
+
+    // The midpoint prepartition is the better initial guess for a normal
+    // distribution.
+    assert!(midpoint_iter < uniform_iter);
+}
+
+#[test]
+fn logspace_prepartition_convergence() {
+    // Power laws spread their area over many decades.
+    for &a in &[1.0, 0.9] {
+        let f = move |x: f64| x.powf(-a);
+        let df = move |x: f64| -a * x.powf(-a - 1.0);
+        let (x0, x1) = (1.0e-6, 1.0);
+
+        let mut midpoint_iter = 0;
+        let x_init = midpoint_prepartition::<P64<f64>, _, _>(&f, x0, x1, 0);
+        TabulationBuilder::new(&f, &df, &x_init, &[])
+            .max_iter(200)
+            .progress(|iter, _| midpoint_iter = iter)
+            .build()
+            .unwrap();
+
+        let mut logspace_iter = 0;
+        let x_init = logspace_prepartition::<P64<f64>, _, _>(&f, x0, x1);
+        assert_eq!(x_init[0], x0);
+        assert_eq!(x_init[64], x1);
+        TabulationBuilder::new(&f, &df, &x_init, &[])
+            .max_iter(200)
+            .progress(|iter, _| logspace_iter = iter)
+            .build()
+            .unwrap();
+
+        assert!(logspace_iter < midpoint_iter);
+    }
+}
+
+#[test]
+fn logspace_prepartition_negative_bounds() {
+    let x = logspace_prepartition::<P16<f64>, _, _>(&pdf, -100.0, -0.01);
+
+    assert_eq!(x[0], -100.0);
+    assert_eq!(x[16], -0.01);
+    for i in 0..16 {
+        assert!(x[i] < x[i + 1]);
+        assert!((x[i + 1] / x[i] - 0.1_f64.powf(0.25)).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn logspace_prepartition_bad_bounds() {
+    let bad_bounds = [
+        (0.0, 1.0),
+        (-1.0, 0.0),
+        (-1.0, 1.0),
+        (2.0, 1.0),
+        (1.0, 1.0),
+        (1.0, f64::INFINITY),
+        (f64::NAN, 1.0),
+    ];
+
+    for &(x0, x1) in &bad_bounds {
+        let result =
+            std::panic::catch_unwind(|| logspace_prepartition::<P16<f64>, _, _>(&pdf, x0, x1));
+        assert!(result.is_err(), "x0 = {}, x1 = {}", x0, x1);
+    }
+}
+
+#[test]
+fn composite_prepartition_nodes() {
+    let x_init = composite_prepartition::<P64<f64>, _, _>(&pdf, -4.0, 4.0, &[-1.0, 0.5]);
+
+    // 64 sub-intervals are split as 22, 21 and 21 sub-intervals.
+    assert_eq!(x_init[0], -4.0);
+    assert_eq!(x_init[22], -1.0);
+    assert_eq!(x_init[43], 0.5);
+    assert_eq!(x_init[64], 4.0);
+    for i in 0..64 {
+        assert!(x_init[i] < x_init[i + 1]);
+    }
+    assert!((x_init[1] - x_init[0] - 3.0 / 22.0).abs() < 1.0e-12);
+    assert!((x_init[23] - x_init[22] - 1.5 / 21.0).abs() < 1.0e-12);
+    assert!((x_init[44] - x_init[43] - 3.5 / 21.0).abs() < 1.0e-12);
+}
+
+// Returns the minimum number of iterations required for convergence.
+fn min_iter<F: Fn(u32) -> bool>(converges: F) -> u32 {
+    (0..=50).find(|&m| converges(m)).unwrap()
+}
+
+// Checks that the second-order method requires fewer iterations.
+fn assert_faster_second_order<F, DF, DDF>(
+    f: F,
+    df: DF,
+    ddf: DDF,
+    x0: f64,
+    x1: f64,
+    x_extrema: &[f64],
+) where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+    DDF: Fn(f64) -> f64,
+{
+    let x_init = midpoint_prepartition::<P256<f64>, _, _>(&f, x0, x1, 0);
+    for &tolerance in &[1.0e-4, 1.0e-8] {
+        let first_order =
+            min_iter(|m| newton_tabulation(&f, &df, &x_init, x_extrema, tolerance, 1.0, m).is_ok());
+        let second_order = min_iter(|m| {
+            second_order_newton_tabulation(&f, &df, &ddf, &x_init, x_extrema, tolerance, 1.0, m)
+                .is_ok()
+        });
+
+        assert!(second_order < first_order);
+    }
+}
+
+#[test]
+fn second_order_newton_tabulation_normal() {
+    assert_faster_second_order(
+        |x| (-0.5 * x * x).exp(),
+        |x| -x * (-0.5 * x * x).exp(),
+        |x| (x * x - 1.0) * (-0.5 * x * x).exp(),
+        0.0,
+        3.5,
+        &[],
+    );
+}
+
+#[test]
+fn second_order_newton_tabulation_gumbel() {
+    assert_faster_second_order(
+        |x| (-x - (-x).exp()).exp(),
+        |x| ((-x).exp() - 1.0) * (-x - (-x).exp()).exp(),
+        |x| {
+            let e = (-x).exp();
+            ((e - 1.0) * (e - 1.0) - e) * (-x - e).exp()
+        },
+        -1.7,
+        5.5,
+        &[0.0],
+    );
+}
+
+#[test]
+fn second_order_newton_tabulation_gamma() {
+    // Non-normalized gamma PDF with shape 5 and scale 1.
+    assert_faster_second_order(
+        |x| x.powi(4) * (-x).exp(),
+        |x| (4.0 - x) * x.powi(3) * (-x).exp(),
+        |x| (x * x - 8.0 * x + 12.0) * x * x * (-x).exp(),
+        0.0,
+        15.0,
+        &[4.0],
+    );
+}
+
+#[test]
+fn hermite_prepartition_nodes() {
+    let f = |x: f64| pdf((x - 1.5) / 2.0);
+    let x = hermite_prepartition::<P256<f64>, _, _>(&f, 1.5, 2.0, 4.0);
+
+    assert_eq!(x[0], 1.5 - 8.0);
+    assert_eq!(x[256], 1.5 + 8.0);
+    assert!((0..256).all(|i| x[i] < x[i + 1]));
+    // The nodes are symmetric about the mean.
+    for i in 0..=256 {
+        assert!(((x[i] - 1.5) + (x[256 - i] - 1.5)).abs() < 1.0e-10);
+    }
+    // The nodes are denser near the mean.
+    assert!(x[129] - x[128] < x[1] - x[0]);
+}
+
+// Checks that the Hermite prepartition of a normal function converges as
+// fast as the midpoint prepartition.
+fn assert_hermite_convergence<P: Partition<f64>>(k: f64) {
+    let tolerance = 1.0e-8;
+    let x_midpoint = midpoint_prepartition::<P, _, _>(&pdf, -k, k, 0);
+    let x_hermite = hermite_prepartition::<P, _, _>(&pdf, 0.0, 1.0, k);
+
+    let midpoint_iter = min_iter(|m| {
+        newton_tabulation(&pdf, &dpdf, &x_midpoint, &[0.0], tolerance, 1.0, m).is_ok()
+    });
+    let hermite_iter =
+        min_iter(|m| newton_tabulation(&pdf, &dpdf, &x_hermite, &[0.0], tolerance, 1.0, m).is_ok());
+
+    assert_eq!(hermite_iter, midpoint_iter, "n = {}, k = {}", P::SIZE, k);
+}
+
+#[test]
+fn hermite_prepartition_convergence() {
+    for &k in &[2.0, 4.0, 6.0] {
+        assert_hermite_convergence::<P16<f64>>(k);
+        assert_hermite_convergence::<P64<f64>>(k);
+        assert_hermite_convergence::<P256<f64>>(k);
+    }
+}
+
+#[test]
+fn pdf_area_normal() {
+    use etf::num::Float;
+
+    // ∫_{-1}^{2} exp(-x²/2) dx = √(π/2) [erf(√2) + erf(1/√2)].
+    let expected = (0.5 * std::f64::consts::PI).sqrt()
+        * (Float::erf(2.0_f64.sqrt()) + Float::erf(0.5_f64.sqrt()));
+
+    let coarse = pdf_area(&pdf, -1.0, 2.0, 10);
+    let fine = pdf_area(&pdf, -1.0, 2.0, 100);
+    assert!((coarse - expected).abs() < 1.0e-4 * expected);
+    assert!((fine - expected).abs() < 1.0e-8 * expected);
+
+    let adaptive = pdf_area_adaptive(&pdf, -1.0, 2.0, 1.0e-10).unwrap();
+    assert!((adaptive - expected).abs() < 1.0e-10 * expected);
+}
+
+#[test]
+fn pdf_area_weibull_envelope() {
+    // The area does not depend on the PDF.
+    let zero = |_: f64| 0.0;
+    let envelopes = [
+        WeibullEnvelope::new(1.5, 2.0, 2.0_f64.sqrt(), 0.0, 1.0, zero),
+        WeibullEnvelope::new(0.5, 1.0, -1.0, 2.0, 1.0, zero),
+        WeibullEnvelope::new(2.0, 3.0, 0.7, -1.0, -0.5, zero),
+    ];
+    let ranges = [(1.0, 20.0), (-50.0, 1.0), (-0.5, 10.0)];
+
+    for (envelope, &(x0, x1)) in envelopes.iter().zip(ranges.iter()) {
+        let area = pdf_area_adaptive(envelope, x0, x1, 1.0e-10).unwrap();
+        assert!((area - envelope.area()).abs() < 1.0e-8 * envelope.area());
+    }
+}
+
+#[test]
+fn weibull_envelope_mirrored_area() {
+    // A negative scale mirrors the envelope to the left of `cut_in`.
+    let envelope = WeibullEnvelope::new(1.5, 2.0, -1.3, 1.0, 0.5, |_: f64| 0.0);
+    let expected = pdf_area_adaptive(&envelope, -60.0, 0.5, 1.0e-10).unwrap();
+
+    assert!(envelope.area() > 0.0);
+    assert!((envelope.area() - expected).abs() < 1.0e-8 * expected);
+}
+
+#[test]
+fn pdf_area_adaptive_singularity() {
+    // Non-integrable singularity at x=0.
+    let f = |x: f64| 1.0 / x;
+
+    assert!(pdf_area_adaptive(&f, 0.0, 1.0, 1.0e-8).is_err());
+}
+
+#[test]
+fn newton_tabulation_poor_initial_partition() {
+    // Laplace function with a regular initial partition, which is very far
+    // from the solution in the tails.
+    let laplace = |x: f64| (-x.abs()).exp();
+    let dlaplace = |x: f64| -x.signum() * (-x.abs()).exp();
+    let mut x_init = midpoint_prepartition::<P128<f64>, _, _>(&laplace, -20.0, 20.0, 0);
+    for i in 0..=128 {
+        x_init[i] = -20.0 + 40.0 * i as f64 / 128.0;
+    }
+
+    assert!(newton_tabulation(&laplace, &dlaplace, &x_init, &[0.0], 1.0e-8, 1.0, 60).is_ok());
+}
+
+#[test]
+fn newton_tabulation_removable_singularity() {
+    // sinc function over [-3, 3] and its derivative, both evaluating to 0/0
+    // at the maximum x=0.
+    let sinc = |x: f64| x.sin() / x;
+    let dsinc = |x: f64| (x * x.cos() - x.sin()) / (x * x);
+    let f = SingularPdf::new(sinc, vec![(0.0, 1.0)], 1.0e-12);
+    let df = SingularPdf::new(dsinc, vec![(0.0, 0.0)], 1.0e-12);
+    assert_eq!(f.eval(0.0), 1.0);
+    assert_eq!(df.eval(0.0), 0.0);
+    assert_eq!(f.eval(1.0), sinc(1.0));
+
+    let x_init = midpoint_prepartition::<P128<f64>, _, _>(&f, -3.0, 3.0, 0);
+    let table = newton_tabulation(&f, &df, &x_init, &[0.0], 1.0e-8, 1.0, 50).unwrap();
+
+    // The subintervals adjacent to the maximum are bounded by its limit.
+    assert!(table.x[64].abs() < 1.0e-12);
+    assert!((table.ysup[63] - 1.0).abs() < 1.0e-12);
+    assert!((table.ysup[64] - 1.0).abs() < 1.0e-12);
+    for i in 0..128 {
+        assert!(table.yinf[i].is_finite() && table.ysup[i].is_finite());
+    }
+}
+
+#[test]
+fn midpoint_prepartition_vanishing_interval() {
+    // Function vanishing over [1, 2].
+    let f = |x: f64| if (1.0..=2.0).contains(&x) { 0.0 } else { 1.0 };
+    let x = try_midpoint_prepartition::<P64<f64>, _, _>(&f, 0.0, 3.0, 0).unwrap();
+
+    for i in 0..=64 {
+        assert!(x[i].is_finite());
+        assert!(x[i] <= 1.0 || x[i] >= 2.0, "x[{}] = {}", i, x[i]);
+    }
+}
+
+#[test]
+fn midpoint_prepartition_reversed_interval() {
+    let x = try_midpoint_prepartition::<P64<f64>, _, _>(&pdf, 3.0, 0.0, 0).unwrap();
+    let x_ref = midpoint_prepartition::<P64<f64>, _, _>(&pdf, 0.0, 3.0, 0);
+
+    assert_eq!(x[0], 3.0);
+    assert_eq!(x[64], 0.0);
+    for i in 0..64 {
+        assert!(x[i] > x[i + 1]);
+    }
+    // Both partitions split the area under the same curve into equal parts.
+    for i in 1..64 {
+        assert!(
+            (x[i] - x_ref[64 - i]).abs() < 1.0e-12,
+            "x[{}] = {}",
+            i,
+            x[i]
+        );
+    }
+}
+
+#[test]
+fn midpoint_prepartition_zero_function() {
+    let f = |_: f64| 0.0;
+
+    assert!(try_midpoint_prepartition::<P64<f64>, _, _>(&f, 0.0, 3.0, 0).is_err());
+}
@@ -0,0 +1,73 @@
+//! Compile-time checks that the distributions are `Send` and `Sync`.
+//!
+//! The checks are performed within a constant closure that is never called,
+//! so this file only needs to compile.
+
+use etf::distributions::*;
+use etf::primitives::partition::P256;
+use etf::primitives::util::{SumEnvelope, WeibullEnvelope};
+use etf::primitives::*;
+
+type Func = fn(f64) -> f64;
+type Envelope = WeibullEnvelope<f64, Func>;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+// Primitive distributions and envelopes.
+const _: fn() = || {
+    assert_send_sync::<DistAny<P256<f64>, f64, Func>>();
+    assert_send_sync::<DistAnyTailed<P256<f64>, f64, Func, Envelope>>();
+    assert_send_sync::<DistHalfAny<P256<f64>, f64, Func>>();
+    assert_send_sync::<DistHalfAnyTailed<P256<f64>, f64, Func, Envelope>>();
+    assert_send_sync::<DistCentral<P256<f64>, f64, Func>>();
+    assert_send_sync::<DistCentralTailed<P256<f64>, f64, Func, Envelope>>();
+    assert_send_sync::<DistSymmetric<P256<f64>, f64, Func>>();
+    assert_send_sync::<DistSymmetricTailed<P256<f64>, f64, Func, Envelope>>();
+    assert_send_sync::<Envelope>();
+    assert_send_sync::<SumEnvelope<f64, Envelope, Envelope>>();
+    assert_send_sync::<LogTransformedDist<DistAny<P256<f64>, f64, LogTransformedFn<Func>>>>();
+};
+
+// Built-in distributions.
+const _: fn() = || {
+    assert_send_sync::<Cauchy<f32>>();
+    assert_send_sync::<Cauchy<f64>>();
+    assert_send_sync::<CentralNormal<f32>>();
+    assert_send_sync::<CentralNormal<f64>>();
+    assert_send_sync::<ChiDist<f32>>();
+    assert_send_sync::<ChiDist<f64>>();
+    assert_send_sync::<ChiDistDirect<f32>>();
+    assert_send_sync::<ChiDistDirect<f64>>();
+    assert_send_sync::<ChiSquared<f32>>();
+    assert_send_sync::<ChiSquared<f64>>();
+    assert_send_sync::<Gamma<f32>>();
+    assert_send_sync::<Gamma<f64>>();
+    assert_send_sync::<Gumbel<f32>>();
+    assert_send_sync::<Gumbel<f64>>();
+    assert_send_sync::<HalfCauchy<f32>>();
+    assert_send_sync::<HalfCauchy<f64>>();
+    assert_send_sync::<InverseChi2<f32>>();
+    assert_send_sync::<InverseChi2<f64>>();
+    assert_send_sync::<Kumaraswamy<f32>>();
+    assert_send_sync::<Kumaraswamy<f64>>();
+    assert_send_sync::<KumaraswamyETF<f32>>();
+    assert_send_sync::<KumaraswamyETF<f64>>();
+    assert_send_sync::<LogGamma<f32>>();
+    assert_send_sync::<LogGamma<f64>>();
+    assert_send_sync::<LogOddsBeta<f32>>();
+    assert_send_sync::<LogOddsBeta<f64>>();
+    assert_send_sync::<LogRatioNormal<f32>>();
+    assert_send_sync::<LogRatioNormal<f64>>();
+    assert_send_sync::<MaxwellBoltzmann<f32>>();
+    assert_send_sync::<MaxwellBoltzmann<f64>>();
+    assert_send_sync::<Normal<f32>>();
+    assert_send_sync::<Normal<f64>>();
+    assert_send_sync::<ScaledInverseChi2<f32>>();
+    assert_send_sync::<ScaledInverseChi2<f64>>();
+    assert_send_sync::<SymmetricStable<f32>>();
+    assert_send_sync::<SymmetricStable<f64>>();
+    assert_send_sync::<WrappedCauchy<f32>>();
+    assert_send_sync::<WrappedCauchy<f64>>();
+    assert_send_sync::<WrappedNormal<f32>>();
+    assert_send_sync::<WrappedNormal<f64>>();
+};